@@ -0,0 +1,433 @@
+//! Lock-free trackers of integer and `bool` values, built on atomics.
+//!
+//! [`AtomicCurrentPreviousU32`] and [`AtomicCurrentPreviousBool`] hold both
+//! values in a single atomic, so they are always read together consistently.
+//!
+//! The `u64` and `i64` trackers hold the `current` and `previous` values in
+//! separate atomics, so each is read atomically, but not together with the
+//! other. They suit a single thread updating while any number of threads
+//! read: a read may then see a new `current` value alongside the `previous`
+//! value from before the update, and `delta` may be the difference between
+//! values that were never held together. While updates are made from more
+//! than one thread at once, the `previous` value, and the value returned by
+//! an update, may not be the one held directly before. For consistent pairs
+//! of wider values, use a
+//! [`SeqLockCurrentPrevious`](crate::seqlock::SeqLockCurrentPrevious).
+
+use std::hint;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicU8, Ordering};
+
+use crate::CurrentPrevious;
+
+macro_rules! atomic_current_previous {
+	($name:ident, $atomic:ty, $t:ty) => {
+		#[doc = concat!("Tracks a `current` `", stringify!($t), "` along with the `previous` value that it replaced, without locking.")]
+		///
+		/// The two values are read separately, so see the
+		/// [module documentation](self) for the consistency of reads made
+		/// while updates are made.
+		///
+		/// # Examples
+		///
+		/// ```
+		#[doc = concat!("# use current_previous::atomic::", stringify!($name), ";")]
+		#[doc = concat!("let current_previous = ", stringify!($name), "::new(1);")]
+		///
+		/// assert_eq!(current_previous.update(4), None);
+		///
+		/// assert_eq!(current_previous.current(), 4);
+		/// assert_eq!(current_previous.previous(), Some(1));
+		/// assert_eq!(current_previous.delta(), Some(3));
+		/// ```
+		#[derive(Debug)]
+		pub struct $name {
+			current: $atomic,
+			previous: $atomic,
+			has_previous: AtomicBool
+		}
+
+		impl $name {
+			#[doc = concat!("Creates a new `", stringify!($name), "` holding the `initial` value as its `current` value, with no `previous` value.")]
+			pub const fn new(initial: $t) -> Self {
+				return Self {
+					current: <$atomic>::new(initial),
+					previous: <$atomic>::new(0),
+					has_previous: AtomicBool::new(false)
+				};
+			}
+
+			/// Gets the `current` value.
+			pub fn current(&self) -> $t {
+				return self.current.load(Ordering::Acquire);
+			}
+
+			/// Gets the `previous` value, if there is one.
+			pub fn previous(&self) -> Option<$t> {
+				if !self.has_previous.load(Ordering::Acquire) {
+					return None;
+				}
+
+				return Some(self.previous.load(Ordering::Acquire));
+			}
+
+			/// Sets a new `current` value, making the old `current` value the
+			/// `previous` value. Returns the old `previous` value, if there
+			/// was one.
+			pub fn update(&self, new: $t) -> Option<$t> {
+				let old = self.current.swap(new, Ordering::AcqRel);
+				let evicted = self.previous.swap(old, Ordering::AcqRel);
+
+				if self.has_previous.swap(true, Ordering::AcqRel) {
+					return Some(evicted);
+				}
+
+				return None;
+			}
+
+			/// Computes `current - previous`. Returns `None` if there is no
+			/// `previous` value, or if overflow occurred.
+			///
+			/// The two values are read separately, so while updates are made,
+			/// this is not a consistent snapshot: it may be the difference
+			/// between values from different updates.
+			pub fn delta(&self) -> Option<$t> {
+				let previous = self.previous()?;
+
+				return self.current().checked_sub(previous);
+			}
+
+			/// Consumes the tracker, returning the held values as a
+			/// `CurrentPrevious`.
+			pub fn into_inner(self) -> CurrentPrevious<$t> {
+				let previous = self.previous();
+
+				return CurrentPrevious::new_with_previous(self.current.into_inner(), previous);
+			}
+		}
+
+		impl From<$t> for $name {
+			fn from(initial: $t) -> Self {
+				return Self::new(initial);
+			}
+		}
+	};
+}
+
+atomic_current_previous!(AtomicCurrentPreviousU64, AtomicU64, u64);
+atomic_current_previous!(AtomicCurrentPreviousI64, AtomicI64, i64);
+
+const PREVIOUS_NONE: u8 = 0;
+const PREVIOUS_PENDING: u8 = 1;
+const PREVIOUS_SOME: u8 = 2;
+
+/// Tracks a `current` `u32` along with the `previous` value that it replaced,
+/// without locking.
+///
+/// Both values are held in a single `AtomicU64`, so they are always read
+/// together consistently, and [`delta`](Self::delta) is always the difference
+/// between values that were held together. The first update briefly makes
+/// other threads wait for it, as it also records that there is now a
+/// `previous` value; later reads and updates never wait.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::atomic::AtomicCurrentPreviousU32;
+/// let current_previous = AtomicCurrentPreviousU32::new(1);
+///
+/// assert_eq!(current_previous.update(4), None);
+///
+/// assert_eq!(current_previous.pair(), (4, Some(1)));
+/// assert_eq!(current_previous.delta(), Some(3));
+/// ```
+#[derive(Debug)]
+pub struct AtomicCurrentPreviousU32 {
+	state: AtomicU64,
+	status: AtomicU8
+}
+
+impl AtomicCurrentPreviousU32 {
+	/// Creates a new `AtomicCurrentPreviousU32` holding the `initial` value as
+	/// its `current` value, with no `previous` value.
+	pub const fn new(initial: u32) -> Self {
+		return Self {
+			state: AtomicU64::new(pack(initial, 0)),
+			status: AtomicU8::new(PREVIOUS_NONE)
+		};
+	}
+
+	/// Gets the `current` value.
+	pub fn current(&self) -> u32 {
+		return unpack(self.state.load(Ordering::Acquire)).0;
+	}
+
+	/// Gets the `previous` value, if there is one.
+	pub fn previous(&self) -> Option<u32> {
+		return self.pair().1;
+	}
+
+	/// Gets the `current` and `previous` values, read together.
+	pub fn pair(&self) -> (u32, Option<u32>) {
+		loop {
+			let status = self.status.load(Ordering::Acquire);
+			let (current, previous) = unpack(self.state.load(Ordering::Acquire));
+
+			match status {
+				PREVIOUS_SOME => return (current, Some(previous)),
+				// The first update had not started when the values were read
+				// if it still has not started after.
+				PREVIOUS_NONE if self.status.load(Ordering::Acquire) == PREVIOUS_NONE => return (current, None),
+				_ => hint::spin_loop()
+			}
+		}
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value. Returns the old `previous` value, if there was one.
+	pub fn update(&self, new: u32) -> Option<u32> {
+		loop {
+			match self.status.load(Ordering::Acquire) {
+				PREVIOUS_SOME => break,
+				PREVIOUS_NONE => {
+					if self.status.compare_exchange(PREVIOUS_NONE, PREVIOUS_PENDING, Ordering::Acquire, Ordering::Relaxed).is_ok() {
+						let (current, _) = unpack(self.state.load(Ordering::Relaxed));
+
+						self.state.store(pack(new, current), Ordering::Release);
+						self.status.store(PREVIOUS_SOME, Ordering::Release);
+
+						return None;
+					}
+				},
+				_ => hint::spin_loop()
+			}
+		}
+
+		let old = self.state.fetch_update(Ordering::AcqRel, Ordering::Acquire, |old| {
+			return Some(pack(new, unpack(old).0));
+		}).expect("the update closure always returns Some");
+
+		return Some(unpack(old).1);
+	}
+
+	/// Computes `current - previous` from values read together. Returns `None`
+	/// if there is no `previous` value, or if overflow occurred.
+	pub fn delta(&self) -> Option<u32> {
+		let (current, previous) = self.pair();
+
+		return current.checked_sub(previous?);
+	}
+
+	/// Consumes the tracker, returning the held values as a
+	/// `CurrentPrevious`.
+	pub fn into_inner(self) -> CurrentPrevious<u32> {
+		let (current, previous) = self.pair();
+
+		return CurrentPrevious::new_with_previous(current, previous);
+	}
+}
+
+impl From<u32> for AtomicCurrentPreviousU32 {
+	fn from(initial: u32) -> Self {
+		return Self::new(initial);
+	}
+}
+
+const fn pack(current: u32, previous: u32) -> u64 {
+	return (current as u64) << 32 | previous as u64;
+}
+
+const fn unpack(state: u64) -> (u32, u32) {
+	return ((state >> 32) as u32, state as u32);
+}
+
+const CURRENT: u8 = 0b001;
+const PREVIOUS: u8 = 0b010;
+const HAS_PREVIOUS: u8 = 0b100;
+
+/// Tracks a `current` `bool` along with the `previous` value that it
+/// replaced, without locking.
+///
+/// Both values are held in a single atomic, so they are always read together
+/// consistently.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::atomic::AtomicCurrentPreviousBool;
+/// let online = AtomicCurrentPreviousBool::new(false);
+///
+/// online.update(true);
+///
+/// assert!(online.current());
+/// assert_eq!(online.previous(), Some(false));
+/// assert!(online.changed());
+/// ```
+#[derive(Debug)]
+pub struct AtomicCurrentPreviousBool {
+	state: AtomicU8
+}
+
+impl AtomicCurrentPreviousBool {
+	/// Creates a new `AtomicCurrentPreviousBool` holding the `initial` value
+	/// as its `current` value, with no `previous` value.
+	pub const fn new(initial: bool) -> Self {
+		return Self {
+			state: AtomicU8::new(if initial { CURRENT } else { 0 })
+		};
+	}
+
+	/// Gets the `current` value.
+	pub fn current(&self) -> bool {
+		return self.state.load(Ordering::Acquire) & CURRENT != 0;
+	}
+
+	/// Gets the `previous` value, if there is one.
+	pub fn previous(&self) -> Option<bool> {
+		return Self::decode(self.state.load(Ordering::Acquire)).1;
+	}
+
+	/// Gets the `current` and `previous` values, read together.
+	pub fn pair(&self) -> (bool, Option<bool>) {
+		return Self::decode(self.state.load(Ordering::Acquire));
+	}
+
+	/// Returns whether the `current` and `previous` values differ, which is
+	/// `false` if there is no `previous` value.
+	pub fn changed(&self) -> bool {
+		let (current, previous) = self.pair();
+
+		return previous.is_some_and(|previous| previous != current);
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value. Returns the old `previous` value, if there was one.
+	pub fn update(&self, new: bool) -> Option<bool> {
+		let mut new_state = HAS_PREVIOUS;
+
+		if new {
+			new_state |= CURRENT;
+		}
+
+		let old = self.state.fetch_update(Ordering::AcqRel, Ordering::Acquire, |old| {
+			return Some(new_state | if old & CURRENT != 0 { PREVIOUS } else { 0 });
+		}).expect("the update closure always returns Some");
+
+		return Self::decode(old).1;
+	}
+
+	/// Consumes the tracker, returning the held values as a
+	/// `CurrentPrevious`.
+	pub fn into_inner(self) -> CurrentPrevious<bool> {
+		let (current, previous) = Self::decode(self.state.into_inner());
+
+		return CurrentPrevious::new_with_previous(current, previous);
+	}
+
+	fn decode(state: u8) -> (bool, Option<bool>) {
+		let previous = (state & HAS_PREVIOUS != 0).then_some(state & PREVIOUS != 0);
+
+		return (state & CURRENT != 0, previous);
+	}
+}
+
+impl From<bool> for AtomicCurrentPreviousBool {
+	fn from(initial: bool) -> Self {
+		return Self::new(initial);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use std::thread;
+
+	#[test]
+	fn integers() {
+		let current_previous = AtomicCurrentPreviousI64::new(-5);
+
+		assert_eq!(current_previous.previous(), None);
+		assert_eq!(current_previous.delta(), None);
+		assert_eq!(current_previous.update(-8), None);
+		assert_eq!(current_previous.delta(), Some(-3));
+		assert_eq!(current_previous.update(0), Some(-5));
+		assert_eq!(current_previous.into_inner().into_parts(), (0, Some(-8)));
+
+		let overflowing = AtomicCurrentPreviousU32::from(1);
+
+		overflowing.update(0);
+
+		assert_eq!(overflowing.delta(), None);
+	}
+
+	#[test]
+	fn concurrent_reads() {
+		let current_previous = AtomicCurrentPreviousU64::new(0);
+
+		thread::scope(|scope| {
+			let reader = scope.spawn(|| {
+				let mut last = 0;
+
+				while last < 10_000 {
+					let current = current_previous.current();
+
+					assert!(current >= last);
+					if let Some(previous) = current_previous.previous() {
+						assert!(previous < 10_000);
+					}
+
+					last = current;
+				}
+			});
+
+			for value in 1..=10_000 {
+				current_previous.update(value);
+			}
+
+			reader.join().unwrap();
+		});
+
+		assert_eq!(current_previous.into_inner().into_parts(), (10_000, Some(9_999)));
+	}
+
+	#[test]
+	fn consistent_u32_pairs() {
+		let current_previous = AtomicCurrentPreviousU32::new(0);
+
+		thread::scope(|scope| {
+			for _ in 0..2 {
+				scope.spawn(|| {
+					for _ in 0..10_000 {
+						let (current, previous) = current_previous.pair();
+
+						match previous {
+							Some(previous) => assert_eq!(current, previous + 1),
+							None => assert_eq!(current, 0)
+						}
+
+						assert!(matches!(current_previous.delta(), None | Some(1)));
+					}
+				});
+			}
+
+			for value in 1..=10_000 {
+				assert_eq!(current_previous.update(value), value.checked_sub(2));
+			}
+		});
+
+		assert_eq!(current_previous.into_inner().into_parts(), (10_000, Some(9_999)));
+	}
+
+	#[test]
+	fn bool() {
+		let current_previous = AtomicCurrentPreviousBool::from(true);
+
+		assert_eq!(current_previous.pair(), (true, None));
+		assert!(!current_previous.changed());
+		assert_eq!(current_previous.update(true), None);
+		assert!(!current_previous.changed());
+		assert_eq!(current_previous.update(false), Some(true));
+		assert_eq!(current_previous.pair(), (false, Some(true)));
+		assert_eq!(current_previous.into_inner().into_parts(), (false, Some(true)));
+	}
+}
@@ -0,0 +1,150 @@
+//! Descriptions of the change between the values held by a `CurrentPrevious`.
+
+use std::cmp::Ordering;
+
+use crate::CurrentPrevious;
+
+/// The state of a `CurrentPrevious`, as returned by
+/// [`CurrentPrevious::change`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Change<'a, T> {
+	/// There is no `previous` value.
+	Initial,
+	/// The `previous` value is equal to the `current` value.
+	Unchanged,
+	/// The `previous` value differs from the `current` value.
+	Changed {
+		/// The `previous` value.
+		from: &'a T,
+		/// The `current` value.
+		to: &'a T
+	}
+}
+
+/// The direction of the change between the values held by a
+/// `CurrentPrevious`, as returned by [`CurrentPrevious::trend`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Trend {
+	/// The `current` value is greater than the `previous` value.
+	Increasing,
+	/// The `current` value is less than the `previous` value.
+	Decreasing,
+	/// The `current` value is equal to the `previous` value.
+	Unchanged
+}
+
+impl <T: PartialEq> CurrentPrevious<T> {
+	/// Describes the state of the `CurrentPrevious` as a [`Change`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// # use current_previous::change::Change;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// assert_eq!(current_previous.change(), Change::Initial);
+	///
+	/// current_previous.update(1);
+	///
+	/// assert_eq!(current_previous.change(), Change::Changed { from: &0, to: &1 });
+	///
+	/// current_previous.update(1);
+	///
+	/// assert_eq!(current_previous.change(), Change::Unchanged);
+	/// ```
+	pub fn change(&self) -> Change<'_, T> {
+		return match self.pair() {
+			(_, None) => Change::Initial,
+			(current, Some(previous)) if current == previous => Change::Unchanged,
+			(current, Some(previous)) => Change::Changed {
+				from: previous,
+				to: current
+			}
+		};
+	}
+
+	/// Describes the direction of the change from the `previous` value to the
+	/// `current` value as a [`Trend`]. Returns `None` if there is no
+	/// `previous` value, or if the values cannot be compared.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// # use current_previous::change::Trend;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// assert_eq!(current_previous.trend(), None);
+	///
+	/// current_previous.update(1);
+	///
+	/// assert_eq!(current_previous.trend(), Some(Trend::Increasing));
+	///
+	/// current_previous.update(-1);
+	///
+	/// assert_eq!(current_previous.trend(), Some(Trend::Decreasing));
+	/// ```
+	pub fn trend(&self) -> Option<Trend> where T: PartialOrd {
+		let (current, previous) = self.pair();
+
+		return match current.partial_cmp(previous?)? {
+			Ordering::Greater => Some(Trend::Increasing),
+			Ordering::Less => Some(Trend::Decreasing),
+			Ordering::Equal => Some(Trend::Unchanged)
+		};
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn change() {
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		assert_eq!(current_previous.change(), Change::Initial);
+
+		current_previous.update(String::from("a"));
+
+		assert_eq!(current_previous.change(), Change::Unchanged);
+
+		current_previous.update(String::from("b"));
+
+		match current_previous.change() {
+			Change::Changed { from, to } => {
+				assert_eq!(from, "a");
+				assert_eq!(to, "b");
+			},
+			change => panic!("unexpected {change:?}")
+		}
+
+		current_previous.clear_previous();
+
+		assert_eq!(current_previous.change(), Change::Initial);
+	}
+
+	#[test]
+	fn trend() {
+		let mut current_previous = CurrentPrevious::new(1.0);
+
+		assert_eq!(current_previous.trend(), None);
+
+		current_previous.update(1.0);
+
+		assert_eq!(current_previous.trend(), Some(Trend::Unchanged));
+
+		current_previous.update(f64::NAN);
+
+		assert_eq!(current_previous.trend(), None);
+
+		current_previous.update(2.0);
+
+		assert_eq!(current_previous.trend(), None);
+
+		current_previous.update(3.0);
+
+		assert_eq!(current_previous.trend(), Some(Trend::Increasing));
+	}
+}
@@ -0,0 +1,152 @@
+//! Sources of the current time for trackers that record when they were
+//! updated.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+/// A point in time that can be recorded by a tracker.
+///
+/// This is implemented for the monotonic [`Instant`], and for the wall-clock
+/// [`SystemTime`], which can be displayed to users and stored.
+pub trait Timestamp: Copy + Ord + Hash + Debug {
+	/// Gets the time elapsed from `earlier` to `self`, or zero if `earlier` is
+	/// later than `self`.
+	fn elapsed_since(&self, earlier: &Self) -> Duration;
+}
+
+impl Timestamp for Instant {
+	fn elapsed_since(&self, earlier: &Self) -> Duration {
+		return self.saturating_duration_since(*earlier);
+	}
+}
+
+impl Timestamp for SystemTime {
+	fn elapsed_since(&self, earlier: &Self) -> Duration {
+		return SystemTime::duration_since(self, *earlier).unwrap_or(Duration::ZERO);
+	}
+}
+
+/// A source of the current time.
+pub trait Clock {
+	/// The type of time that this clock tells.
+	type Timestamp: Timestamp;
+
+	/// Gets the current time.
+	fn now(&self) -> Self::Timestamp;
+}
+
+/// A [`Clock`] that tells the time with the monotonic [`Instant`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MonotonicClock;
+
+impl Clock for MonotonicClock {
+	type Timestamp = Instant;
+
+	fn now(&self) -> Instant {
+		return Instant::now();
+	}
+}
+
+/// A [`Clock`] that tells the time with the wall-clock [`SystemTime`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+	type Timestamp = SystemTime;
+
+	fn now(&self) -> SystemTime {
+		return SystemTime::now();
+	}
+}
+
+/// A [`Clock`] that only moves when it is told to, for testing time-dependent
+/// behavior deterministically.
+///
+/// Clones of a `MockClock` share the same time, so a test can keep a clone to
+/// advance the clock of a tracker that it has handed one to.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::clock::{Clock, MockClock};
+/// # use std::time::Duration;
+/// let clock = MockClock::new();
+/// let start = clock.now();
+///
+/// clock.clone().advance(Duration::from_secs(5));
+///
+/// assert_eq!(clock.now(), start + Duration::from_secs(5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MockClock {
+	start: Instant,
+	elapsed: Arc<Mutex<Duration>>
+}
+
+impl MockClock {
+	/// Creates a new `MockClock`, starting at the current [`Instant`].
+	pub fn new() -> Self {
+		return Self {
+			start: Instant::now(),
+			elapsed: Arc::new(Mutex::new(Duration::ZERO))
+		};
+	}
+
+	/// Moves the clock forward by `duration`.
+	pub fn advance(&self, duration: Duration) {
+		*self.elapsed.lock().unwrap_or_else(|error| error.into_inner()) += duration;
+	}
+
+	/// Gets the time that the clock has been moved forward by since it was
+	/// created.
+	pub fn elapsed(&self) -> Duration {
+		return *self.elapsed.lock().unwrap_or_else(|error| error.into_inner());
+	}
+}
+
+impl Default for MockClock {
+	fn default() -> Self {
+		return Self::new();
+	}
+}
+
+impl Clock for MockClock {
+	type Timestamp = Instant;
+
+	fn now(&self) -> Instant {
+		return self.start + self.elapsed();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn mock_clock() {
+		let clock = MockClock::default();
+		let start = clock.now();
+
+		assert_eq!(clock.now(), start);
+
+		clock.advance(Duration::from_millis(1500));
+
+		let shared = clock.clone();
+
+		shared.advance(Duration::from_millis(500));
+
+		assert_eq!(clock.elapsed(), Duration::from_secs(2));
+		assert_eq!(shared.now(), start + Duration::from_secs(2));
+	}
+
+	#[test]
+	fn elapsed_since() {
+		let earlier = SystemTime::UNIX_EPOCH;
+		let later = earlier + Duration::from_secs(3);
+
+		assert_eq!(later.elapsed_since(&earlier), Duration::from_secs(3));
+		assert_eq!(earlier.elapsed_since(&later), Duration::ZERO);
+	}
+}
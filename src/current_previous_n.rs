@@ -0,0 +1,275 @@
+//! A tracker of a `current` value and up to `N` `previous` values.
+
+use crate::iter::NewestFirst;
+use crate::ring::Ring;
+
+/// Tracks a `current` value along with up to `N` of the `previous` values that
+/// it replaced, from the most recent to the oldest.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::CurrentPreviousN;
+/// let mut current_previous: CurrentPreviousN<i32, 2> = CurrentPreviousN::new(0);
+///
+/// current_previous.update(1);
+/// current_previous.update(2);
+/// current_previous.update(3);
+///
+/// assert_eq!(current_previous.current(), &3);
+/// assert_eq!(current_previous.nth_previous(0), Some(&2));
+/// assert_eq!(current_previous.nth_previous(1), Some(&1));
+/// assert_eq!(current_previous.nth_previous(2), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CurrentPreviousN<T, const N: usize> {
+	current: T,
+	previous: Ring<T, N>
+}
+
+// A `CurrentPreviousN` always holds at least its `current` value, so it is
+// never empty.
+#[allow(clippy::len_without_is_empty)]
+impl <T, const N: usize> CurrentPreviousN<T, N> {
+	/// Creates a new `CurrentPreviousN` holding the `initial` value as its
+	/// `current` value, with no `previous` values.
+	pub const fn new(initial: T) -> Self {
+		return Self {
+			current: initial,
+			previous: Ring::new()
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return &self.current;
+	}
+
+	/// Gets a mutable reference to the `current` value.
+	pub fn current_mut(&mut self) -> &mut T {
+		return &mut self.current;
+	}
+
+	/// Gets an optional reference to the most recent `previous` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.nth_previous(0);
+	}
+
+	/// Gets an optional reference to the `n`th `previous` value, where `0` is
+	/// the most recent `previous` value.
+	pub fn nth_previous(&self, n: usize) -> Option<&T> {
+		return self.previous.get(n);
+	}
+
+	/// Gets an optional mutable reference to the `n`th `previous` value, where
+	/// `0` is the most recent `previous` value.
+	pub fn nth_previous_mut(&mut self, n: usize) -> Option<&mut T> {
+		return self.previous.get_mut(n);
+	}
+
+	/// Gets the number of held values, including the `current` value, which
+	/// is at most `N + 1`.
+	pub fn len(&self) -> usize {
+		return self.previous.len() + 1;
+	}
+
+	/// Gets the number of `previous` values held, which is at most `N`.
+	pub fn previous_len(&self) -> usize {
+		return self.previous.len();
+	}
+
+	/// Gets the maximum number of `previous` values that can be held, which is
+	/// `N`.
+	pub const fn capacity(&self) -> usize {
+		return N;
+	}
+
+	/// Returns whether `N` `previous` values are held, so the next update will
+	/// drop the oldest.
+	pub fn is_full(&self) -> bool {
+		return self.previous.is_full();
+	}
+
+	/// Gets a reference to the oldest held value, which is the `current` value
+	/// if no `previous` values are held.
+	pub fn oldest(&self) -> &T {
+		return self.previous_len().checked_sub(1).and_then(|n| self.nth_previous(n)).unwrap_or(&self.current);
+	}
+
+	/// Returns an iterator over the held values, from the `current` value to
+	/// the oldest `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPreviousN;
+	/// let mut current_previous: CurrentPreviousN<i32, 2> = CurrentPreviousN::new(0);
+	///
+	/// current_previous.update(1);
+	/// current_previous.update(2);
+	/// current_previous.update(3);
+	///
+	/// assert_eq!(current_previous.iter().collect::<Vec<_>>(), vec![&3, &2, &1]);
+	/// assert_eq!(current_previous.iter().rev().collect::<Vec<_>>(), vec![&1, &2, &3]);
+	/// ```
+	pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+		return NewestFirst::new(&self.current, self.previous.iter());
+	}
+
+	/// Sets a new `current` value, making the old `current` value the most
+	/// recent `previous` value. If `N` `previous` values were already held,
+	/// the oldest is returned, as it is no longer held.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPreviousN;
+	/// let mut current_previous: CurrentPreviousN<i32, 2> = CurrentPreviousN::new(0);
+	///
+	/// assert_eq!(current_previous.update(1), None);
+	/// assert_eq!(current_previous.update(2), None);
+	/// assert_eq!(current_previous.update(3), Some(0));
+	/// ```
+	pub fn update(&mut self, new: T) -> Option<T> {
+		let old = std::mem::replace(&mut self.current, new);
+
+		return self.previous.push(old);
+	}
+
+	/// Sets a new `current` value, as though it were passed to
+	/// [`update`](Self::update), only if it differs from the `current` value,
+	/// so that consecutive equal values are recorded once. Returns whether
+	/// the update took place.
+	pub fn update_if_changed(&mut self, new: T) -> bool where T: PartialEq {
+		if self.current() == &new {
+			return false;
+		}
+
+		self.update(new);
+
+		return true;
+	}
+
+	/// Replaces `self` with a new `CurrentPreviousN` constructed from the given
+	/// `new` value.
+	pub fn reset(&mut self, new: T) {
+		*self = Self::new(new);
+	}
+
+	/// Removes all of the `previous` values.
+	pub fn clear_previous(&mut self) {
+		self.previous.clear();
+	}
+}
+
+impl <T: Default, const N: usize> Default for CurrentPreviousN<T, N> {
+	fn default() -> Self {
+		return Self::new(T::default());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn update() {
+		let mut current_previous: CurrentPreviousN<i32, 3> = CurrentPreviousN::new(0);
+
+		assert_eq!(current_previous.previous(), None);
+		assert_eq!(current_previous.previous_len(), 0);
+
+		for value in 1..=5 {
+			current_previous.update(value);
+		}
+
+		assert_eq!(current_previous.current(), &5);
+		assert_eq!(current_previous.previous(), Some(&4));
+		assert_eq!(current_previous.nth_previous(1), Some(&3));
+		assert_eq!(current_previous.nth_previous(2), Some(&2));
+		assert_eq!(current_previous.nth_previous(3), None);
+		assert_eq!(current_previous.previous_len(), 3);
+	}
+
+	#[test]
+	fn zero_depth() {
+		let mut current_previous: CurrentPreviousN<i32, 0> = CurrentPreviousN::new(0);
+
+		assert_eq!(current_previous.update(1), Some(0));
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), None);
+	}
+
+	#[test]
+	fn update_if_changed() {
+		let mut current_previous: CurrentPreviousN<i32, 3> = CurrentPreviousN::new(0);
+
+		for value in [0, 1, 1, 2] {
+			current_previous.update_if_changed(value);
+		}
+
+		assert!(!current_previous.update_if_changed(2));
+		assert_eq!(current_previous.iter().collect::<Vec<_>>(), vec![&2, &1, &0]);
+	}
+
+	#[test]
+	fn reset_and_clear() {
+		let mut current_previous: CurrentPreviousN<String, 2> = CurrentPreviousN::default();
+
+		current_previous.update(String::from("a"));
+		current_previous.nth_previous_mut(0).unwrap().push('!');
+
+		assert_eq!(current_previous.previous().map(String::as_str), Some("!"));
+
+		current_previous.clear_previous();
+
+		assert_eq!(current_previous.previous_len(), 0);
+		assert_eq!(current_previous.current(), "a");
+
+		current_previous.update(String::from("b"));
+		current_previous.reset(String::from("c"));
+
+		assert_eq!(current_previous.current(), "c");
+		assert_eq!(current_previous.previous(), None);
+	}
+
+	#[test]
+	fn occupancy() {
+		let mut current_previous: CurrentPreviousN<i32, 2> = CurrentPreviousN::new(0);
+
+		assert_eq!(current_previous.capacity(), 2);
+		assert_eq!(current_previous.oldest(), &0);
+
+		current_previous.update(1);
+
+		assert!(!current_previous.is_full());
+		assert_eq!(current_previous.oldest(), &0);
+		assert_eq!(current_previous.len(), 2);
+
+		current_previous.update(2);
+		current_previous.update(3);
+
+		assert!(current_previous.is_full());
+		assert_eq!(current_previous.oldest(), &1);
+	}
+
+	#[test]
+	fn iter() {
+		let mut current_previous: CurrentPreviousN<i32, 2> = CurrentPreviousN::new(0);
+
+		assert_eq!(current_previous.iter().collect::<Vec<_>>(), vec![&0]);
+
+		for value in 1..=3 {
+			current_previous.update(value);
+		}
+
+		let mut iter = current_previous.iter();
+
+		assert_eq!(iter.len(), 3);
+		assert_eq!(iter.next_back(), Some(&1));
+		assert_eq!(iter.next(), Some(&3));
+		assert_eq!(iter.next_back(), Some(&2));
+		assert_eq!(iter.len(), 0);
+		assert_eq!(iter.next(), None);
+	}
+}
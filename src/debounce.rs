@@ -0,0 +1,165 @@
+//! Coalescing of updates that arrive in quick succession.
+
+use std::time::Duration;
+
+use crate::CurrentPrevious;
+use crate::clock::{Clock, MonotonicClock, Timestamp};
+
+/// Tracks a `current` value along with the `previous` value that it replaced,
+/// only committing a new value once no further updates have arrived for a
+/// quiet window.
+///
+/// Updates are held as pending until they are committed by
+/// [`settle`](Self::settle), or by a later update arriving after the window
+/// has passed. An update that arrives within the window replaces the pending
+/// value and restarts the window.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::clock::MockClock;
+/// # use current_previous::debounce::Debounced;
+/// # use std::time::Duration;
+/// let clock = MockClock::new();
+///
+/// let mut debounced = Debounced::with_clock("", Duration::from_millis(100), clock.clone());
+///
+/// debounced.update("h");
+/// clock.advance(Duration::from_millis(50));
+/// debounced.update("hi");
+///
+/// assert!(!debounced.settle());
+/// assert_eq!(debounced.current(), &"");
+///
+/// clock.advance(Duration::from_millis(100));
+///
+/// assert!(debounced.settle());
+/// assert_eq!(debounced.current(), &"hi");
+/// assert_eq!(debounced.previous(), Some(&""));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Debounced<T, C: Clock = MonotonicClock> {
+	values: CurrentPrevious<T>,
+	pending: Option<(T, C::Timestamp)>,
+	window: Duration,
+	clock: C
+}
+
+impl <T> Debounced<T> {
+	/// Creates a new `Debounced` holding the `initial` value as its `current`
+	/// value, which commits updates once they have been quiet for `window`.
+	pub fn new(initial: T, window: Duration) -> Self {
+		return Self::with_clock(initial, window, MonotonicClock);
+	}
+}
+
+impl <T, C: Clock> Debounced<T, C> {
+	/// Creates a new `Debounced` holding the `initial` value as its `current`
+	/// value, which commits updates once they have been quiet for `window`,
+	/// as told by the given clock.
+	pub fn with_clock(initial: T, window: Duration, clock: C) -> Self {
+		return Self {
+			values: CurrentPrevious::new(initial),
+			pending: None,
+			window,
+			clock
+		};
+	}
+
+	/// Gets a reference to the committed `current` value.
+	pub fn current(&self) -> &T {
+		return self.values.current();
+	}
+
+	/// Gets an optional reference to the committed `previous` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.values.previous();
+	}
+
+	/// Gets an optional reference to the pending value, which has not been
+	/// committed yet.
+	pub fn pending(&self) -> Option<&T> {
+		return self.pending.as_ref().map(|(value, _)| value);
+	}
+
+	/// Gets a reference to the committed values.
+	pub fn values(&self) -> &CurrentPrevious<T> {
+		return &self.values;
+	}
+
+	/// Gets the window for which updates must be quiet to be committed.
+	pub fn window(&self) -> Duration {
+		return self.window;
+	}
+
+	/// Sets a new pending value, replacing any pending value that has not
+	/// been quiet for the window yet. A pending value that has been quiet for
+	/// the window is committed first.
+	pub fn update(&mut self, new: T) {
+		self.settle();
+
+		self.pending = Some((new, self.clock.now()));
+	}
+
+	/// Commits the pending value if it has been quiet for the window, making
+	/// the old `current` value the `previous` value. Returns whether a value
+	/// was committed.
+	pub fn settle(&mut self) -> bool {
+		let now = self.clock.now();
+
+		let Some((_, at)) = &self.pending else {
+			return false;
+		};
+
+		if now.elapsed_since(at) < self.window {
+			return false;
+		}
+
+		return self.flush();
+	}
+
+	/// Commits the pending value immediately, regardless of the window.
+	/// Returns whether there was a pending value.
+	pub fn flush(&mut self) -> bool {
+		let Some((new, _)) = self.pending.take() else {
+			return false;
+		};
+
+		self.values.update(new);
+
+		return true;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::clock::MockClock;
+
+	#[test]
+	fn coalesce() {
+		let clock = MockClock::new();
+
+		let mut debounced = Debounced::with_clock(0, Duration::from_secs(1), clock.clone());
+
+		assert!(!debounced.settle());
+
+		debounced.update(1);
+		debounced.update(2);
+
+		assert_eq!(debounced.pending(), Some(&2));
+
+		clock.advance(Duration::from_secs(1));
+		debounced.update(3);
+
+		assert_eq!(debounced.current(), &2);
+		assert_eq!(debounced.previous(), Some(&0));
+		assert_eq!(debounced.pending(), Some(&3));
+
+		assert!(debounced.flush());
+		assert!(!debounced.flush());
+		assert_eq!(debounced.values().pair(), (&3, Some(&2)));
+		assert_eq!(debounced.current(), &3);
+	}
+}
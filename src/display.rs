@@ -0,0 +1,177 @@
+//! Formatting of the values held by a `CurrentPrevious`.
+
+use std::fmt;
+
+use crate::CurrentPrevious;
+
+/// A configurable formatter for the values held by a `CurrentPrevious`.
+///
+/// The template may contain the placeholders `{current}` and `{previous}`,
+/// which are replaced by the formatted values. All other text is written as
+/// is. When there is no `previous` value, the initial template is used
+/// instead, which defaults to `"{current}"`.
+///
+/// This `struct` is created by [`CurrentPrevious::display_with`].
+#[derive(Clone, Copy, Debug)]
+pub struct ChangeDisplay<'a, T> {
+	current_previous: &'a CurrentPrevious<T>,
+	template: &'a str,
+	initial_template: &'a str
+}
+
+impl <'a, T> ChangeDisplay<'a, T> {
+	/// Sets the template used when there is no `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let current_previous = CurrentPrevious::new(5);
+	///
+	/// let display = current_previous
+	///     .display_with("{previous} -> {current}")
+	///     .when_initial("{current} (new)");
+	///
+	/// assert_eq!(display.to_string(), "5 (new)");
+	/// ```
+	pub fn when_initial(self, initial_template: &'a str) -> Self {
+		return Self {
+			initial_template,
+			..self
+		};
+	}
+}
+
+impl <T: fmt::Display> fmt::Display for ChangeDisplay<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (current, previous) = self.current_previous.pair();
+
+		let mut rest = match previous {
+			Some(_) => self.template,
+			None => self.initial_template
+		};
+
+		while let Some(start) = rest.find('{') {
+			f.write_str(&rest[..start])?;
+			rest = &rest[start..];
+
+			if let Some(after) = rest.strip_prefix("{current}") {
+				current.fmt(f)?;
+				rest = after;
+			} else if let Some(after) = rest.strip_prefix("{previous}") {
+				if let Some(previous) = previous {
+					previous.fmt(f)?;
+				}
+				rest = after;
+			} else {
+				f.write_str("{")?;
+				rest = &rest[1..];
+			}
+		}
+
+		return f.write_str(rest);
+	}
+}
+
+impl <T> CurrentPrevious<T> {
+	/// Returns a [`ChangeDisplay`] that formats the held values according to
+	/// the given template.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(3);
+	///
+	/// assert_eq!(current_previous.display_with("{current} ← {previous}").to_string(), "3");
+	///
+	/// current_previous.update(5);
+	///
+	/// assert_eq!(current_previous.display_with("{current} ← {previous}").to_string(), "5 ← 3");
+	/// ```
+	pub fn display_with<'a>(&'a self, template: &'a str) -> ChangeDisplay<'a, T> {
+		return ChangeDisplay {
+			current_previous: self,
+			template,
+			initial_template: "{current}"
+		};
+	}
+}
+
+impl <T: fmt::Display> fmt::Display for CurrentPrevious<T> {
+	/// Formats the `current` value, followed by the `previous` value in
+	/// parentheses if there is one. Formatting options are applied to both
+	/// values.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(3);
+	///
+	/// assert_eq!(current_previous.to_string(), "3");
+	///
+	/// current_previous.update(5);
+	///
+	/// assert_eq!(current_previous.to_string(), "5 (was 3)");
+	/// ```
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (current, previous) = self.pair();
+
+		current.fmt(f)?;
+
+		if let Some(previous) = previous {
+			f.write_str(" (was ")?;
+			previous.fmt(f)?;
+			f.write_str(")")?;
+		}
+
+		return Ok(());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::CurrentPrevious;
+
+	#[test]
+	fn display() {
+		let mut current_previous = CurrentPrevious::new("a");
+
+		assert_eq!(format!("{current_previous}"), "a");
+
+		current_previous.update("b");
+
+		assert_eq!(format!("{current_previous}"), "b (was a)");
+	}
+
+	#[test]
+	fn display_with_options() {
+		let current_previous = CurrentPrevious::new_with_previous(1.0, Some(0.5));
+
+		assert_eq!(format!("{current_previous:.2}"), "1.00 (was 0.50)");
+	}
+
+	#[test]
+	fn display_with() {
+		let mut current_previous = CurrentPrevious::new(1);
+
+		let display = current_previous.display_with("[{previous}] -> [{current}]");
+
+		assert_eq!(display.to_string(), "1");
+		assert_eq!(display.when_initial("[] -> [{current}]").to_string(), "[] -> [1]");
+		assert_eq!(display.when_initial("{previous}").to_string(), "");
+
+		current_previous.update(2);
+
+		assert_eq!(current_previous.display_with("[{previous}] -> [{current}]").to_string(), "[1] -> [2]");
+	}
+
+	#[test]
+	fn display_with_literal_braces() {
+		let current_previous = CurrentPrevious::new_with_previous(2, Some(1));
+
+		assert_eq!(current_previous.display_with("{ {current}, {other} }").to_string(), "{ 2, {other} }");
+		assert_eq!(current_previous.display_with("{previous}{").to_string(), "1{");
+	}
+}
@@ -0,0 +1,107 @@
+//! Guarded in-place mutation of the `current` value of a `CurrentPrevious`.
+
+use std::ops::{Deref, DerefMut};
+
+use crate::CurrentPrevious;
+
+/// A smart pointer to the `current` value of a `CurrentPrevious` that records
+/// an update when dropped, if the value was mutably accessed.
+///
+/// The first mutable access clones the original `current` value. When the
+/// guard is dropped, that clone becomes the `previous` value, as though the
+/// mutated value were passed to [`update`](CurrentPrevious::update). If the
+/// value was never mutably accessed, the `CurrentPrevious` is left unchanged.
+///
+/// This `struct` is created by [`CurrentPrevious::modify`].
+#[derive(Debug)]
+pub struct ChangeGuard<'a, T: Clone> {
+	current_previous: &'a mut CurrentPrevious<T>,
+	original: Option<T>
+}
+
+impl <T: Clone> Deref for ChangeGuard<'_, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		return self.current_previous.current();
+	}
+}
+
+impl <T: Clone> DerefMut for ChangeGuard<'_, T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		if self.original.is_none() {
+			self.original = Some(self.current_previous.current().clone());
+		}
+
+		return self.current_previous.current_mut();
+	}
+}
+
+impl <T: Clone> Drop for ChangeGuard<'_, T> {
+	fn drop(&mut self) {
+		if let Some(original) = self.original.take() {
+			self.current_previous.set_previous(original);
+		}
+	}
+}
+
+impl <T: Clone> CurrentPrevious<T> {
+	/// Returns a [`ChangeGuard`] through which the `current` value can be
+	/// mutated in place, recording an update when the guard is dropped if a
+	/// write occurred.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(vec![1]);
+	///
+	/// {
+	///     let guard = current_previous.modify();
+	///
+	///     assert_eq!(guard.len(), 1);
+	/// }
+	///
+	/// assert_eq!(current_previous.previous(), None);
+	///
+	/// current_previous.modify().push(2);
+	///
+	/// assert_eq!(current_previous.current(), &vec![1, 2]);
+	/// assert_eq!(current_previous.previous(), Some(&vec![1]));
+	/// ```
+	pub fn modify(&mut self) -> ChangeGuard<'_, T> {
+		return ChangeGuard {
+			current_previous: self,
+			original: None
+		};
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::CurrentPrevious;
+
+	#[test]
+	fn modify_without_write() {
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		drop(current_previous.modify());
+
+		assert_eq!(current_previous.previous(), None);
+	}
+
+	#[test]
+	fn modify_with_writes() {
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		{
+			let mut guard = current_previous.modify();
+
+			guard.push('b');
+			guard.push('c');
+		}
+
+		assert_eq!(current_previous.current(), "abc");
+		assert_eq!(current_previous.previous().map(String::as_str), Some("a"));
+	}
+}
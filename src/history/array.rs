@@ -0,0 +1,193 @@
+use crate::ring::Ring;
+
+/// Tracks up to `N` of the values that it has held, including the `current`
+/// value, in a fixed-size array, dropping the oldest once full.
+///
+/// Unlike [`HistoryBuffer`](super::HistoryBuffer), an `ArrayHistory` never
+/// allocates, which suits targets without an allocator.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::history::ArrayHistory;
+/// let mut history: ArrayHistory<i32, 3> = ArrayHistory::new(0);
+///
+/// history.update(1);
+/// history.update(2);
+///
+/// assert_eq!(history.update(3), Some(0));
+///
+/// assert_eq!(history.current(), &3);
+/// assert_eq!(history.previous(), Some(&2));
+/// assert_eq!(history.len(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ArrayHistory<T, const N: usize> {
+	values: Ring<T, N>
+}
+
+// An `ArrayHistory` always holds at least its `current` value, so it is never
+// empty.
+#[allow(clippy::len_without_is_empty)]
+impl <T, const N: usize> ArrayHistory<T, N> {
+	/// Creates a new `ArrayHistory` holding the `initial` value as its
+	/// `current` value.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is zero.
+	pub fn new(initial: T) -> Self {
+		assert!(N > 0, "an ArrayHistory must have room for its current value");
+
+		let mut values = Ring::new();
+
+		values.push(initial);
+
+		return Self {
+			values
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return self.values.get(0).expect("an ArrayHistory is never empty");
+	}
+
+	/// Gets a mutable reference to the `current` value.
+	pub fn current_mut(&mut self) -> &mut T {
+		return self.values.get_mut(0).expect("an ArrayHistory is never empty");
+	}
+
+	/// Gets an optional reference to the `previous` value, which is the value
+	/// held before the `current` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.nth_previous(0);
+	}
+
+	/// Gets an optional reference to the `n`th `previous` value, where `0` is
+	/// the most recent `previous` value.
+	pub fn nth_previous(&self, n: usize) -> Option<&T> {
+		return self.values.get(n.checked_add(1)?);
+	}
+
+	/// Gets the number of held values, including the `current` value.
+	pub fn len(&self) -> usize {
+		return self.values.len();
+	}
+
+	/// Gets the maximum number of values that can be held, including the
+	/// `current` value, which is `N`.
+	pub const fn capacity(&self) -> usize {
+		return N;
+	}
+
+	/// Returns whether `N` values are held, so the next update will drop the
+	/// oldest.
+	pub fn is_full(&self) -> bool {
+		return self.values.is_full();
+	}
+
+	/// Gets a reference to the oldest held value, which is the `current` value
+	/// if no `previous` values are held.
+	pub fn oldest(&self) -> &T {
+		return self.values.get(self.values.len() - 1).expect("an ArrayHistory is never empty");
+	}
+
+	/// Returns an iterator over the held values, from the `current` value to
+	/// the oldest.
+	pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+		return self.values.iter();
+	}
+
+	/// Sets a new `current` value. If `N` values were already held, the oldest
+	/// is returned, as it is no longer held.
+	pub fn update(&mut self, new: T) -> Option<T> {
+		return self.values.push(new);
+	}
+
+	/// Sets a new `current` value if it differs from the `current` value,
+	/// returning whether it did.
+	pub fn update_if_changed(&mut self, new: T) -> bool where T: PartialEq {
+		if self.current() == &new {
+			return false;
+		}
+
+		self.update(new);
+
+		return true;
+	}
+
+	/// Creates a new `ArrayHistory` as though the given values, from the
+	/// oldest to the `current` value, were passed to [`update`](Self::update)
+	/// in turn, so only the last `N` are held. Returns `None` if there are no
+	/// values.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is zero.
+	pub fn from_values(values: impl IntoIterator<Item = T>) -> Option<Self> {
+		let mut values = values.into_iter();
+
+		let mut history = Self::new(values.next()?);
+
+		for value in values {
+			history.update(value);
+		}
+
+		return Some(history);
+	}
+
+	/// Copies the held values into a `Vec`, from the oldest to the `current`
+	/// value.
+	pub fn to_vec(&self) -> Vec<T> where T: Clone {
+		return self.iter().rev().cloned().collect();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fixed_capacity() {
+		let mut history: ArrayHistory<&str, 2> = ArrayHistory::new("a");
+
+		assert_eq!(history.update("b"), None);
+		assert_eq!(history.update("c"), Some("a"));
+		assert!(!history.update_if_changed("c"));
+
+		*history.current_mut() = "d";
+
+		assert_eq!(history.iter().copied().collect::<Vec<_>>(), vec!["d", "b"]);
+		assert_eq!(history.nth_previous(1), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_capacity() {
+		let _: ArrayHistory<i32, 0> = ArrayHistory::new(0);
+	}
+
+	#[test]
+	fn occupancy() {
+		let mut history: ArrayHistory<i32, 2> = ArrayHistory::new(0);
+
+		assert_eq!(history.capacity(), 2);
+		assert!(!history.is_full());
+		assert_eq!(history.oldest(), &0);
+
+		history.update(1);
+		history.update(2);
+
+		assert!(history.is_full());
+		assert_eq!(history.oldest(), &1);
+	}
+
+	#[test]
+	fn export_import() {
+		let history: ArrayHistory<i32, 2> = ArrayHistory::from_values([0, 1, 2]).unwrap();
+
+		assert_eq!(history.to_vec(), vec![1, 2]);
+		assert!(ArrayHistory::<i32, 2>::from_values([]).is_none());
+	}
+}
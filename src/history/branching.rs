@@ -0,0 +1,317 @@
+/// A value held by a [`BranchingHistory`], along with its place in the tree.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Node<T> {
+	value: T,
+	parent: Option<usize>,
+	children: Vec<usize>,
+	/// The index into `children` of the branch that redoing returns to.
+	active: Option<usize>
+}
+
+/// Tracks every value that it has held as a tree, so that updating after
+/// undoing starts a new branch instead of discarding what could be redone.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::history::BranchingHistory;
+/// let mut history = BranchingHistory::new("draft");
+///
+/// history.update("first edit");
+/// history.undo();
+/// history.update("second edit");
+///
+/// assert_eq!(history.current(), &"second edit");
+///
+/// history.undo();
+///
+/// assert_eq!(history.branches().collect::<Vec<_>>(), vec![&"first edit", &"second edit"]);
+///
+/// assert!(history.switch_branch(0));
+/// assert_eq!(history.current(), &"first edit");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BranchingHistory<T> {
+	nodes: Vec<Node<T>>,
+	cursor: usize
+}
+
+// A `BranchingHistory` always holds at least its `current` value, so it is
+// never empty.
+#[allow(clippy::len_without_is_empty)]
+impl <T> BranchingHistory<T> {
+	/// Creates a new `BranchingHistory` holding the `initial` value as its
+	/// `current` value.
+	pub fn new(initial: T) -> Self {
+		return Self {
+			nodes: vec![Node {
+				value: initial,
+				parent: None,
+				children: Vec::new(),
+				active: None
+			}],
+			cursor: 0
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return &self.nodes[self.cursor].value;
+	}
+
+	/// Gets an optional reference to the `previous` value, which would become
+	/// the `current` value if undone.
+	pub fn previous(&self) -> Option<&T> {
+		return self.nth_previous(0);
+	}
+
+	/// Gets an optional reference to the `n`th `previous` value on the path
+	/// from the `current` value to the oldest, where `0` is the most recent
+	/// `previous` value.
+	pub fn nth_previous(&self, n: usize) -> Option<&T> {
+		let mut index = self.cursor;
+
+		for _ in 0..=n {
+			index = self.nodes[index].parent?;
+		}
+
+		return Some(&self.nodes[index].value);
+	}
+
+	/// Gets the number of values held across all branches.
+	pub fn len(&self) -> usize {
+		return self.nodes.len();
+	}
+
+	/// Gets a reference to the oldest held value, from which every branch
+	/// starts.
+	pub fn oldest(&self) -> &T {
+		return &self.nodes[0].value;
+	}
+
+	/// Sets a new `current` value on a new branch from the old `current`
+	/// value. Any existing branches are kept.
+	pub fn update(&mut self, new: T) {
+		let index = self.nodes.len();
+
+		self.nodes.push(Node {
+			value: new,
+			parent: Some(self.cursor),
+			children: Vec::new(),
+			active: None
+		});
+
+		let node = &mut self.nodes[self.cursor];
+
+		node.active = Some(node.children.len());
+		node.children.push(index);
+
+		self.cursor = index;
+	}
+
+	/// Sets a new `current` value, as though it were passed to
+	/// [`update`](Self::update), only if it differs from the `current` value,
+	/// so that consecutive equal values are recorded once, and an equal value
+	/// does not start a new branch. Returns whether the update took place.
+	pub fn update_if_changed(&mut self, new: T) -> bool where T: PartialEq {
+		if self.current() == &new {
+			return false;
+		}
+
+		self.update(new);
+
+		return true;
+	}
+
+	/// Restores the `previous` value as the `current` value, returning whether
+	/// there was anything to undo.
+	pub fn undo(&mut self) -> bool {
+		let Some(parent) = self.nodes[self.cursor].parent else {
+			return false;
+		};
+
+		self.cursor = parent;
+
+		return true;
+	}
+
+	/// Moves to the most recently visited branch from the `current` value,
+	/// returning whether there was anything to redo.
+	pub fn redo(&mut self) -> bool {
+		let node = &self.nodes[self.cursor];
+
+		let Some(active) = node.active else {
+			return false;
+		};
+
+		self.cursor = node.children[active];
+
+		return true;
+	}
+
+	/// Returns whether there is anything to undo.
+	pub fn can_undo(&self) -> bool {
+		return self.nodes[self.cursor].parent.is_some();
+	}
+
+	/// Returns whether there is anything to redo.
+	pub fn can_redo(&self) -> bool {
+		return self.nodes[self.cursor].active.is_some();
+	}
+
+	/// Returns an iterator over the first value of each branch from the
+	/// `current` value, from the oldest branch to the newest.
+	pub fn branches(&self) -> impl ExactSizeIterator<Item = &T> + DoubleEndedIterator {
+		return self.nodes[self.cursor].children.iter().map(|&child| &self.nodes[child].value);
+	}
+
+	/// Moves to the branch from the `current` value with the given index, as
+	/// listed by [`branches`](Self::branches), returning whether there was
+	/// such a branch.
+	pub fn switch_branch(&mut self, index: usize) -> bool {
+		let node = &mut self.nodes[self.cursor];
+
+		let Some(&child) = node.children.get(index) else {
+			return false;
+		};
+
+		node.active = Some(index);
+		self.cursor = child;
+
+		return true;
+	}
+
+	/// Creates a new `BranchingHistory` holding the given values as a single
+	/// branch, from the oldest to the `current` value. Returns `None` if there
+	/// are no values.
+	pub fn from_values(values: impl IntoIterator<Item = T>) -> Option<Self> {
+		let mut values = values.into_iter();
+
+		let mut history = Self::new(values.next()?);
+
+		for value in values {
+			history.update(value);
+		}
+
+		return Some(history);
+	}
+
+	/// Copies the values on the path from the oldest value to the `current`
+	/// value into a `Vec`, in that order. Values on other branches are not
+	/// included.
+	pub fn to_vec(&self) -> Vec<T> where T: Clone {
+		let mut values = Vec::new();
+		let mut index = Some(self.cursor);
+
+		while let Some(node) = index.map(|index| &self.nodes[index]) {
+			values.push(node.value.clone());
+			index = node.parent;
+		}
+
+		values.reverse();
+
+		return values;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn linear() {
+		let mut history = BranchingHistory::new(0);
+
+		assert_eq!(history.len(), 1);
+		assert!(!history.undo());
+		assert!(!history.redo());
+
+		history.update(1);
+		history.update(2);
+
+		assert_eq!(history.previous(), Some(&1));
+		assert!(history.undo());
+		assert!(history.undo());
+		assert!(!history.can_undo());
+
+		assert!(history.redo());
+		assert!(history.redo());
+		assert!(!history.can_redo());
+
+		assert_eq!(history.current(), &2);
+		assert_eq!(history.len(), 3);
+	}
+
+	#[test]
+	fn branches() {
+		let mut history = BranchingHistory::new(0);
+
+		history.update(1);
+		history.update(10);
+		history.undo();
+		history.undo();
+		history.update(2);
+		history.undo();
+
+		assert_eq!(history.branches().collect::<Vec<_>>(), vec![&1, &2]);
+
+		assert!(history.redo());
+		assert_eq!(history.current(), &2);
+
+		history.undo();
+
+		assert!(!history.switch_branch(2));
+		assert!(history.switch_branch(0));
+		assert!(history.redo());
+		assert_eq!(history.current(), &10);
+
+		history.undo();
+		history.undo();
+
+		assert!(history.redo());
+		assert_eq!(history.current(), &1);
+		assert_eq!(history.len(), 4);
+	}
+
+	#[test]
+	fn nth_previous() {
+		let mut history = BranchingHistory::new(0);
+
+		history.update(1);
+		history.undo();
+		history.update(2);
+		history.update(3);
+
+		assert_eq!(history.nth_previous(0), Some(&2));
+		assert_eq!(history.nth_previous(1), Some(&0));
+		assert_eq!(history.nth_previous(2), None);
+		assert_eq!(history.nth_previous(usize::MAX), None);
+		assert_eq!(history.oldest(), &0);
+	}
+
+	#[test]
+	fn update_if_changed() {
+		let mut history = BranchingHistory::new(0);
+
+		history.update(1);
+		history.undo();
+
+		assert!(!history.update_if_changed(0));
+		assert_eq!(history.len(), 2);
+		assert!(history.update_if_changed(2));
+		assert_eq!(history.branches().len(), 0);
+	}
+
+	#[test]
+	fn export_import() {
+		let mut history = BranchingHistory::from_values([0, 1]).unwrap();
+
+		history.update(10);
+		history.undo();
+		history.update(2);
+
+		assert_eq!(history.to_vec(), vec![0, 1, 2]);
+		assert_eq!(BranchingHistory::from_values(history.to_vec()).map(|history| history.len()), Some(3));
+		assert_eq!(BranchingHistory::<i32>::from_values([]), None);
+	}
+}
@@ -0,0 +1,416 @@
+use std::collections::{vec_deque, VecDeque};
+
+/// What a [`HistoryBuffer`] does when it is updated while full.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Eviction {
+	/// The oldest value is dropped to make room for the new value.
+	#[default]
+	DropOldest,
+	/// The update is refused, and the new value is handed back.
+	Refuse
+}
+
+/// Tracks up to a fixed number of the values that it has held, including the
+/// `current` value, applying an [`Eviction`] policy once full.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::history::HistoryBuffer;
+/// let mut history = HistoryBuffer::with_capacity(0, 3);
+///
+/// history.update(1).unwrap();
+/// history.update(2).unwrap();
+///
+/// assert_eq!(history.update(3), Ok(Some(0)));
+///
+/// assert_eq!(history.current(), &3);
+/// assert_eq!(history.previous(), Some(&2));
+/// assert_eq!(history.len(), 3);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HistoryBuffer<T> {
+	values: VecDeque<T>,
+	capacity: usize,
+	eviction: Eviction
+}
+
+// A `HistoryBuffer` always holds at least its `current` value, so it is never
+// empty.
+#[allow(clippy::len_without_is_empty)]
+impl <T> HistoryBuffer<T> {
+	/// Creates a new `HistoryBuffer` holding the `initial` value as its
+	/// `current` value, which holds up to `capacity` values before dropping
+	/// the oldest.
+	///
+	/// # Panics
+	///
+	/// Panics if `capacity` is zero.
+	pub fn with_capacity(initial: T, capacity: usize) -> Self {
+		return Self::with_eviction(initial, capacity, Eviction::DropOldest);
+	}
+
+	/// Creates a new `HistoryBuffer` holding the `initial` value as its
+	/// `current` value, which holds up to `capacity` values before applying
+	/// the given [`Eviction`] policy.
+	///
+	/// # Panics
+	///
+	/// Panics if `capacity` is zero.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::{Eviction, HistoryBuffer};
+	/// let mut history = HistoryBuffer::with_eviction(0, 2, Eviction::Refuse);
+	///
+	/// assert_eq!(history.update(1), Ok(None));
+	/// assert_eq!(history.update(2), Err(2));
+	///
+	/// assert_eq!(history.current(), &1);
+	/// assert_eq!(history.previous(), Some(&0));
+	/// ```
+	pub fn with_eviction(initial: T, capacity: usize, eviction: Eviction) -> Self {
+		assert!(capacity > 0, "a HistoryBuffer must have room for its current value");
+
+		let mut values = VecDeque::with_capacity(capacity);
+
+		values.push_back(initial);
+
+		return Self {
+			values,
+			capacity,
+			eviction
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return self.values.back().expect("a HistoryBuffer is never empty");
+	}
+
+	/// Gets an optional reference to the `previous` value, which is the value
+	/// held before the `current` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.nth_previous(0);
+	}
+
+	/// Gets an optional reference to the `n`th `previous` value, where `0` is
+	/// the most recent `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::HistoryBuffer;
+	/// let mut history = HistoryBuffer::with_capacity(0, 3);
+	///
+	/// history.update(1).unwrap();
+	/// history.update(2).unwrap();
+	///
+	/// assert_eq!(history.nth_previous(0), Some(&1));
+	/// assert_eq!(history.nth_previous(1), Some(&0));
+	/// assert_eq!(history.nth_previous(2), None);
+	/// ```
+	pub fn nth_previous(&self, n: usize) -> Option<&T> {
+		let index = self.values.len().checked_sub(n.checked_add(2)?)?;
+
+		return self.values.get(index);
+	}
+
+	/// Gets the number of held values, including the `current` value.
+	pub fn len(&self) -> usize {
+		return self.values.len();
+	}
+
+	/// Gets the maximum number of values that can be held, including the
+	/// `current` value.
+	pub fn capacity(&self) -> usize {
+		return self.capacity;
+	}
+
+	/// Returns whether as many values are held as can be, so the next update
+	/// will apply the [`Eviction`] policy.
+	pub fn is_full(&self) -> bool {
+		return self.values.len() == self.capacity;
+	}
+
+	/// Gets a reference to the oldest held value, which is the `current` value
+	/// if no `previous` values are held.
+	pub fn oldest(&self) -> &T {
+		return self.values.front().expect("a HistoryBuffer is never empty");
+	}
+
+	/// Gets the [`Eviction`] policy applied when the `HistoryBuffer` is full.
+	pub fn eviction(&self) -> Eviction {
+		return self.eviction;
+	}
+
+	/// Returns an iterator over the held values, from the `current` value to
+	/// the oldest.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::HistoryBuffer;
+	/// let mut history = HistoryBuffer::with_capacity(0, 2);
+	///
+	/// history.update(1).unwrap();
+	/// history.update(2).unwrap();
+	///
+	/// assert_eq!(history.iter().collect::<Vec<_>>(), vec![&2, &1]);
+	/// ```
+	pub fn iter(&self) -> std::iter::Rev<vec_deque::Iter<'_, T>> {
+		return self.values.iter().rev();
+	}
+
+	/// Returns an iterator over the held values, from the oldest to the
+	/// `current` value.
+	pub fn iter_oldest_first(&self) -> vec_deque::Iter<'_, T> {
+		return self.values.iter();
+	}
+
+	/// Sets a new `current` value, keeping the old `current` value in the
+	/// history.
+	///
+	/// If the `HistoryBuffer` is full and its policy is
+	/// [`Eviction::DropOldest`], the oldest value is returned as `Ok(Some(_))`,
+	/// as it is no longer held. If its policy is [`Eviction::Refuse`], nothing
+	/// is changed and the new value is returned as `Err(_)`.
+	pub fn update(&mut self, new: T) -> Result<Option<T>, T> {
+		let mut evicted = None;
+
+		if self.values.len() == self.capacity {
+			match self.eviction {
+				Eviction::DropOldest => evicted = self.values.pop_front(),
+				Eviction::Refuse => return Err(new)
+			}
+		}
+
+		self.values.push_back(new);
+
+		return Ok(evicted);
+	}
+
+	/// Sets a new `current` value, as though it were passed to
+	/// [`update`](Self::update), only if it differs from the `current` value,
+	/// so that consecutive equal values are recorded once.
+	///
+	/// Returns `Ok(true)` if the value was recorded, or `Ok(false)` if it was
+	/// equal to the `current` value. If the update was refused, nothing is
+	/// changed and the new value is returned as `Err(_)`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::HistoryBuffer;
+	/// let mut history = HistoryBuffer::with_capacity(0, 3);
+	///
+	/// for value in [0, 1, 1, 1, 0] {
+	///     history.update_if_changed(value).unwrap();
+	/// }
+	///
+	/// assert_eq!(history.iter().collect::<Vec<_>>(), vec![&0, &1, &0]);
+	/// ```
+	pub fn update_if_changed(&mut self, new: T) -> Result<bool, T> where T: PartialEq {
+		if self.current() == &new {
+			return Ok(false);
+		}
+
+		self.update(new)?;
+
+		return Ok(true);
+	}
+
+	/// Downsamples the older values, keeping the `current` value and the
+	/// `keep_recent` most recent `previous` values intact, and only every
+	/// `every`th value before them, starting from the oldest, which makes room
+	/// for new values without dropping all of the oldest.
+	///
+	/// # Panics
+	///
+	/// Panics if `every` is `0`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::{Eviction, HistoryBuffer};
+	/// let mut history = HistoryBuffer::from_values(0..10, 10, Eviction::Refuse).unwrap();
+	///
+	/// history.compact(2, 3);
+	///
+	/// assert_eq!(history.to_vec(), vec![0, 3, 6, 7, 8, 9]);
+	/// assert!(!history.is_full());
+	/// ```
+	pub fn compact(&mut self, keep_recent: usize, every: usize) {
+		assert!(every > 0, "cannot keep every 0th value of a HistoryBuffer");
+
+		let boundary = self.values.len().saturating_sub(keep_recent.saturating_add(1));
+
+		let mut index = 0;
+
+		self.values.retain(|_| {
+			let kept = index >= boundary || index % every == 0;
+
+			index += 1;
+
+			return kept;
+		});
+	}
+
+	/// Creates a new `HistoryBuffer` holding up to `capacity` values before
+	/// applying the given [`Eviction`] policy, as though the given values,
+	/// from the oldest to the `current` value, were passed to
+	/// [`update`](Self::update) in turn. Returns `None` if there are no
+	/// values.
+	///
+	/// # Panics
+	///
+	/// Panics if `capacity` is zero.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::{Eviction, HistoryBuffer};
+	/// let history = HistoryBuffer::from_values([0, 1, 2], 2, Eviction::DropOldest).unwrap();
+	///
+	/// assert_eq!(history.to_vec(), vec![1, 2]);
+	/// ```
+	pub fn from_values(values: impl IntoIterator<Item = T>, capacity: usize, eviction: Eviction) -> Option<Self> {
+		let mut values = values.into_iter();
+
+		let mut history = Self::with_eviction(values.next()?, capacity, eviction);
+
+		for value in values {
+			if history.update(value).is_err() {
+				break;
+			}
+		}
+
+		return Some(history);
+	}
+
+	/// Copies the held values into a `Vec`, from the oldest to the `current`
+	/// value.
+	pub fn to_vec(&self) -> Vec<T> where T: Clone {
+		return self.values.iter().cloned().collect();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn drop_oldest() {
+		let mut history = HistoryBuffer::with_capacity(0, 2);
+
+		assert_eq!(history.eviction(), Eviction::DropOldest);
+
+		assert_eq!(history.update(1), Ok(None));
+		assert_eq!(history.update(2), Ok(Some(0)));
+		assert_eq!(history.update(3), Ok(Some(1)));
+
+		assert_eq!(history.current(), &3);
+		assert_eq!(history.previous(), Some(&2));
+		assert_eq!(history.len(), 2);
+	}
+
+	#[test]
+	fn iter() {
+		let mut history = HistoryBuffer::with_capacity(0, 3);
+
+		for value in 1..=4 {
+			history.update(value).unwrap();
+		}
+
+		assert_eq!(history.iter().collect::<Vec<_>>(), vec![&4, &3, &2]);
+		assert_eq!(history.iter().rev().collect::<Vec<_>>(), vec![&2, &3, &4]);
+		assert_eq!(history.iter_oldest_first().len(), 3);
+	}
+
+	#[test]
+	fn update_if_changed() {
+		let mut history = HistoryBuffer::with_eviction(0, 2, Eviction::Refuse);
+
+		assert_eq!(history.update_if_changed(0), Ok(false));
+		assert_eq!(history.update_if_changed(1), Ok(true));
+		assert_eq!(history.update_if_changed(1), Ok(false));
+		assert_eq!(history.update_if_changed(2), Err(2));
+	}
+
+	#[test]
+	fn refuse() {
+		let mut history = HistoryBuffer::with_eviction(String::from("a"), 1, Eviction::Refuse);
+
+		assert_eq!(history.update(String::from("b")), Err(String::from("b")));
+
+		assert_eq!(history.current(), "a");
+		assert_eq!(history.previous(), None);
+	}
+
+	#[test]
+	fn capacity_of_one() {
+		let mut history = HistoryBuffer::with_capacity(0, 1);
+
+		assert_eq!(history.update(1), Ok(Some(0)));
+
+		assert_eq!(history.current(), &1);
+		assert_eq!(history.previous(), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_capacity() {
+		HistoryBuffer::with_capacity(0, 0);
+	}
+
+	#[test]
+	fn to_vec() {
+		let mut history = HistoryBuffer::with_capacity(0, 2);
+
+		history.update(1).unwrap();
+		history.update(2).unwrap();
+
+		assert_eq!(history.to_vec(), vec![1, 2]);
+
+		let refused = HistoryBuffer::from_values(history.to_vec().into_iter().chain([3]), 2, Eviction::Refuse).unwrap();
+
+		assert_eq!(refused.to_vec(), vec![1, 2]);
+		assert_eq!(HistoryBuffer::<i32>::from_values([], 2, Eviction::Refuse), None);
+	}
+
+	#[test]
+	fn compact() {
+		let mut history = HistoryBuffer::from_values(0..5, 5, Eviction::DropOldest).unwrap();
+
+		history.compact(usize::MAX, 2);
+
+		assert_eq!(history.len(), 5);
+
+		history.compact(0, 2);
+
+		assert_eq!(history.to_vec(), vec![0, 2, 4]);
+		assert_eq!(history.update(5), Ok(None));
+	}
+
+	#[test]
+	#[should_panic]
+	fn compact_every_zero() {
+		HistoryBuffer::with_capacity(0, 1).compact(0, 0);
+	}
+
+	#[test]
+	fn occupancy() {
+		let mut history = HistoryBuffer::with_capacity(0, 2);
+
+		assert_eq!(history.capacity(), 2);
+		assert!(!history.is_full());
+		assert_eq!(history.oldest(), &0);
+
+		history.update(1).unwrap();
+		history.update(2).unwrap();
+
+		assert!(history.is_full());
+		assert_eq!(history.oldest(), &1);
+	}
+}
@@ -0,0 +1,414 @@
+//! Trackers that hold more of the values they have held than a
+//! `CurrentPrevious`.
+
+use std::collections::BTreeMap;
+
+mod array;
+mod branching;
+mod buffer;
+mod persistent;
+mod timed;
+mod undo;
+
+pub use array::ArrayHistory;
+pub use branching::BranchingHistory;
+pub use buffer::{Eviction, HistoryBuffer};
+pub use persistent::{PersistentHistory, PersistentHistoryIter};
+pub use timed::TimedHistory;
+pub use undo::UndoRedo;
+
+/// Tracks every value that it has held, from the oldest to the `current`
+/// value.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::history::History;
+/// let mut history = History::new(0);
+///
+/// history.update(1);
+/// history.update(2);
+///
+/// assert_eq!(history.current(), &2);
+/// assert_eq!(history.previous(), Some(&1));
+/// assert_eq!(history.all(), &[0, 1, 2]);
+/// assert_eq!(history.len(), 3);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct History<T> {
+	values: Vec<T>,
+	checkpoints: BTreeMap<String, usize>
+}
+
+// A `History` always holds at least its `current` value, so it is never
+// empty.
+#[allow(clippy::len_without_is_empty)]
+impl <T> History<T> {
+	/// Creates a new `History` holding the `initial` value as its `current`
+	/// value.
+	pub fn new(initial: T) -> Self {
+		return Self {
+			values: vec![initial],
+			checkpoints: BTreeMap::new()
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return self.values.last().expect("a History is never empty");
+	}
+
+	/// Gets an optional reference to the `previous` value, which is the value
+	/// held before the `current` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.nth_previous(0);
+	}
+
+	/// Gets an optional reference to the `n`th `previous` value, where `0` is
+	/// the most recent `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::History;
+	/// let mut history = History::new(0);
+	///
+	/// history.update(1);
+	/// history.update(2);
+	///
+	/// assert_eq!(history.nth_previous(0), Some(&1));
+	/// assert_eq!(history.nth_previous(1), Some(&0));
+	/// assert_eq!(history.nth_previous(2), None);
+	/// ```
+	pub fn nth_previous(&self, n: usize) -> Option<&T> {
+		let index = self.values.len().checked_sub(n.checked_add(2)?)?;
+
+		return self.values.get(index);
+	}
+
+	/// Gets all of the held values, from the oldest to the `current` value.
+	pub fn all(&self) -> &[T] {
+		return &self.values;
+	}
+
+	/// Gets the number of held values, including the `current` value.
+	pub fn len(&self) -> usize {
+		return self.values.len();
+	}
+
+	/// Gets a reference to the oldest held value, which is the `current` value
+	/// if no `previous` values are held.
+	pub fn oldest(&self) -> &T {
+		return self.values.first().expect("a History is never empty");
+	}
+
+	/// Returns an iterator over the held values, from the `current` value to
+	/// the oldest.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::History;
+	/// let mut history = History::new(0);
+	///
+	/// history.update(1);
+	/// history.update(2);
+	///
+	/// assert_eq!(history.iter().collect::<Vec<_>>(), vec![&2, &1, &0]);
+	/// ```
+	pub fn iter(&self) -> std::iter::Rev<std::slice::Iter<'_, T>> {
+		return self.values.iter().rev();
+	}
+
+	/// Returns an iterator over the held values, from the oldest to the
+	/// `current` value.
+	pub fn iter_oldest_first(&self) -> std::slice::Iter<'_, T> {
+		return self.values.iter();
+	}
+
+	/// Sets a new `current` value, keeping the old `current` value in the
+	/// history.
+	pub fn update(&mut self, new: T) {
+		self.values.push(new);
+	}
+
+	/// Sets a new `current` value, as though it were passed to
+	/// [`update`](Self::update), only if it differs from the `current` value,
+	/// so that consecutive equal values are recorded once. Returns whether the
+	/// update took place.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::History;
+	/// let mut history = History::new(0);
+	///
+	/// for value in [0, 1, 1, 1, 0] {
+	///     history.update_if_changed(value);
+	/// }
+	///
+	/// assert_eq!(history.all(), &[0, 1, 0]);
+	/// ```
+	pub fn update_if_changed(&mut self, new: T) -> bool where T: PartialEq {
+		if self.current() == &new {
+			return false;
+		}
+
+		self.update(new);
+
+		return true;
+	}
+
+	/// Records a checkpoint with the given name at the `current` value, which
+	/// can later be returned to with
+	/// [`restore_checkpoint`](Self::restore_checkpoint). An existing checkpoint
+	/// with the same name is replaced.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::History;
+	/// let mut history = History::new("start");
+	///
+	/// history.update("step 1");
+	/// history.checkpoint("confirmed");
+	///
+	/// history.update("step 2");
+	/// history.update("step 3");
+	///
+	/// assert!(history.restore_checkpoint("confirmed"));
+	///
+	/// assert_eq!(history.current(), &"step 1");
+	/// assert_eq!(history.all(), &["start", "step 1"]);
+	/// ```
+	pub fn checkpoint(&mut self, name: impl Into<String>) {
+		self.checkpoints.insert(name.into(), self.values.len());
+	}
+
+	/// Returns to the checkpoint with the given name, discarding every value
+	/// recorded after it, along with any checkpoints recorded at those values.
+	/// Returns whether there was a checkpoint with the given name.
+	pub fn restore_checkpoint(&mut self, name: &str) -> bool {
+		let Some(&len) = self.checkpoints.get(name) else {
+			return false;
+		};
+
+		self.values.truncate(len);
+		self.checkpoints.retain(|_, checkpoint| *checkpoint <= len);
+
+		return true;
+	}
+
+	/// Creates a new `History` holding the given values, from the oldest to
+	/// the `current` value. Returns `None` if there are no values.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::History;
+	/// let history = History::from_values([0, 1, 2]).unwrap();
+	///
+	/// assert_eq!(history.current(), &2);
+	/// assert_eq!(history.to_vec(), vec![0, 1, 2]);
+	/// ```
+	pub fn from_values(values: impl IntoIterator<Item = T>) -> Option<Self> {
+		let values: Vec<T> = values.into_iter().collect();
+
+		if values.is_empty() {
+			return None;
+		}
+
+		return Some(Self {
+			values,
+			checkpoints: BTreeMap::new()
+		});
+	}
+
+	/// Copies the held values into a `Vec`, from the oldest to the `current`
+	/// value.
+	pub fn to_vec(&self) -> Vec<T> where T: Clone {
+		return self.values.clone();
+	}
+
+	/// Consumes the `History`, returning the held values from the oldest to
+	/// the `current` value.
+	pub fn into_vec(self) -> Vec<T> {
+		return self.values;
+	}
+
+	/// Downsamples the older values, keeping the `current` value and the
+	/// `keep_recent` most recent `previous` values intact, and only every
+	/// `every`th value before them, starting from the oldest. Checkpoints at
+	/// discarded values are removed.
+	///
+	/// # Panics
+	///
+	/// Panics if `every` is `0`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::History;
+	/// let mut history = History::from_values(0..10).unwrap();
+	///
+	/// history.compact(2, 3);
+	///
+	/// assert_eq!(history.all(), &[0, 3, 6, 7, 8, 9]);
+	/// ```
+	pub fn compact(&mut self, keep_recent: usize, every: usize) {
+		assert!(every > 0, "cannot keep every 0th value of a History");
+
+		let boundary = self.values.len().saturating_sub(keep_recent.saturating_add(1));
+		let kept: Vec<bool> = (0..self.values.len()).map(|index| index >= boundary || index % every == 0).collect();
+
+		let mut index = 0;
+
+		self.values.retain(|_| {
+			index += 1;
+
+			return kept[index - 1];
+		});
+
+		self.checkpoints.retain(|_, len| {
+			if *len == 0 || !kept[*len - 1] {
+				return false;
+			}
+
+			*len = kept[..*len].iter().filter(|kept| **kept).count();
+
+			return true;
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn new() {
+		let history = History::new("a");
+
+		assert_eq!(history.current(), &"a");
+		assert_eq!(history.previous(), None);
+		assert_eq!(history.all(), &["a"]);
+		assert_eq!(history.len(), 1);
+	}
+
+	#[test]
+	fn update() {
+		let mut history = History::new(String::from("a"));
+
+		history.update(String::from("b"));
+		history.update(String::from("c"));
+
+		assert_eq!(history.current(), "c");
+		assert_eq!(history.previous().map(String::as_str), Some("b"));
+		assert_eq!(history.all(), &["a", "b", "c"]);
+	}
+
+	#[test]
+	fn iter() {
+		let mut history = History::new(0);
+
+		history.update(1);
+		history.update(2);
+
+		let mut iter = history.iter();
+
+		assert_eq!(iter.len(), 3);
+		assert_eq!(iter.next(), Some(&2));
+		assert_eq!(iter.next_back(), Some(&0));
+		assert_eq!(iter.len(), 1);
+
+		assert_eq!(history.iter_oldest_first().collect::<Vec<_>>(), vec![&0, &1, &2]);
+	}
+
+	#[test]
+	fn nth_previous() {
+		let history = History::new(0);
+
+		assert_eq!(history.nth_previous(0), None);
+		assert_eq!(history.nth_previous(usize::MAX), None);
+	}
+
+	#[test]
+	fn update_if_changed() {
+		let mut history = History::new(String::from("a"));
+
+		assert!(!history.update_if_changed(String::from("a")));
+		assert!(history.update_if_changed(String::from("b")));
+		assert!(!history.update_if_changed(String::from("b")));
+
+		assert_eq!(history.all(), &["a", "b"]);
+	}
+
+	#[test]
+	fn checkpoints() {
+		let mut history = History::new(0);
+
+		history.checkpoint("zero");
+		history.update(1);
+		history.checkpoint("one");
+		history.update(2);
+		history.checkpoint("two");
+
+		assert!(!history.restore_checkpoint("three"));
+		assert!(history.restore_checkpoint("one"));
+
+		assert_eq!(history.all(), &[0, 1]);
+		assert!(!history.restore_checkpoint("two"));
+
+		history.update(3);
+
+		assert!(history.restore_checkpoint("one"));
+		assert_eq!(history.all(), &[0, 1]);
+
+		assert!(history.restore_checkpoint("zero"));
+		assert_eq!(history.all(), &[0]);
+		assert_eq!(history.previous(), None);
+	}
+
+	#[test]
+	fn export_import() {
+		assert!(History::<i32>::from_values([]).is_none());
+
+		let mut history = History::new(String::from("a"));
+
+		history.update(String::from("b"));
+
+		let restored = History::from_values(history.to_vec()).unwrap();
+
+		assert_eq!(restored.current(), "b");
+		assert_eq!(restored.previous().map(String::as_str), Some("a"));
+		assert_eq!(history.into_vec(), restored.into_vec());
+	}
+
+	#[test]
+	fn compact() {
+		let mut history = History::new(0);
+
+		history.checkpoint("zero");
+		history.update(1);
+		history.checkpoint("one");
+
+		for value in 2..8 {
+			history.update(value);
+		}
+
+		history.checkpoint("seven");
+		history.compact(1, 2);
+
+		assert_eq!(history.all(), &[0, 2, 4, 6, 7]);
+		assert_eq!(history.oldest(), &0);
+		assert!(!history.restore_checkpoint("one"));
+		assert!(history.restore_checkpoint("seven"));
+		assert_eq!(history.all(), &[0, 2, 4, 6, 7]);
+		assert!(history.restore_checkpoint("zero"));
+		assert_eq!(history.all(), &[0]);
+
+		history.compact(0, 5);
+
+		assert_eq!(history.all(), &[0]);
+	}
+}
@@ -0,0 +1,349 @@
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+use std::sync::Arc;
+
+/// A value held by a [`PersistentHistory`], linked to the value before it.
+#[derive(Debug)]
+struct Node<T> {
+	value: T,
+	previous: Option<Arc<Node<T>>>
+}
+
+/// Tracks every value that it has held as a persistent linked list, so that
+/// cloning it is O(1) and clones share the values they have in common.
+///
+/// As the values are shared through [`Arc`]s, a `PersistentHistory` can be
+/// sent to other threads when `T` is [`Send`] and [`Sync`].
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::history::PersistentHistory;
+/// let mut history = PersistentHistory::new(0);
+///
+/// history.update(1);
+///
+/// let snapshot = history.clone();
+///
+/// history.update(2);
+///
+/// assert_eq!(history.iter().collect::<Vec<_>>(), vec![&2, &1, &0]);
+/// assert_eq!(snapshot.iter().collect::<Vec<_>>(), vec![&1, &0]);
+/// ```
+#[derive(Debug)]
+pub struct PersistentHistory<T> {
+	head: Arc<Node<T>>,
+	len: usize
+}
+
+// A `PersistentHistory` always holds at least its `current` value, so it is
+// never empty.
+#[allow(clippy::len_without_is_empty)]
+impl <T> PersistentHistory<T> {
+	/// Creates a new `PersistentHistory` holding the `initial` value as its
+	/// `current` value.
+	pub fn new(initial: T) -> Self {
+		return Self {
+			head: Arc::new(Node {
+				value: initial,
+				previous: None
+			}),
+			len: 1
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return &self.head.value;
+	}
+
+	/// Gets an optional reference to the `previous` value, which is the value
+	/// held before the `current` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.nth_previous(0);
+	}
+
+	/// Gets an optional reference to the `n`th `previous` value, where `0` is
+	/// the most recent `previous` value.
+	pub fn nth_previous(&self, n: usize) -> Option<&T> {
+		return self.iter().nth(n.checked_add(1)?);
+	}
+
+	/// Gets the number of held values, including the `current` value.
+	pub fn len(&self) -> usize {
+		return self.len;
+	}
+
+	/// Gets a reference to the oldest held value, which is the `current` value
+	/// if no `previous` values are held.
+	///
+	/// As the values are linked from the `current` value to the oldest, this
+	/// walks every held value.
+	pub fn oldest(&self) -> &T {
+		return self.iter().last().expect("a PersistentHistory is never empty");
+	}
+
+	/// Returns an iterator over the held values, from the `current` value to
+	/// the oldest.
+	pub fn iter(&self) -> PersistentHistoryIter<'_, T> {
+		return PersistentHistoryIter {
+			node: Some(&self.head),
+			nodes: VecDeque::new(),
+			len: self.len
+		};
+	}
+
+	/// Returns an iterator over the held values, from the oldest to the
+	/// `current` value.
+	///
+	/// As the values are linked from the `current` value to the oldest, they
+	/// are collected before the oldest is yielded.
+	pub fn iter_oldest_first(&self) -> std::iter::Rev<PersistentHistoryIter<'_, T>> {
+		return self.iter().rev();
+	}
+
+	/// Sets a new `current` value, keeping the old `current` value in the
+	/// history. Clones of the `PersistentHistory` are unaffected.
+	pub fn update(&mut self, new: T) {
+		let previous = Arc::clone(&self.head);
+
+		self.head = Arc::new(Node {
+			value: new,
+			previous: Some(previous)
+		});
+		self.len += 1;
+	}
+
+	/// Sets a new `current` value, as though it were passed to
+	/// [`update`](Self::update), only if it differs from the `current` value,
+	/// so that consecutive equal values are recorded once. Returns whether
+	/// the update took place.
+	pub fn update_if_changed(&mut self, new: T) -> bool where T: PartialEq {
+		if self.current() == &new {
+			return false;
+		}
+
+		self.update(new);
+
+		return true;
+	}
+
+	/// Creates a new `PersistentHistory` holding the given values, from the
+	/// oldest to the `current` value. Returns `None` if there are no values.
+	pub fn from_values(values: impl IntoIterator<Item = T>) -> Option<Self> {
+		let mut values = values.into_iter();
+
+		let mut history = Self::new(values.next()?);
+
+		for value in values {
+			history.update(value);
+		}
+
+		return Some(history);
+	}
+
+	/// Copies the held values into a `Vec`, from the oldest to the `current`
+	/// value.
+	pub fn to_vec(&self) -> Vec<T> where T: Clone {
+		let mut values: Vec<T> = self.iter().cloned().collect();
+
+		values.reverse();
+
+		return values;
+	}
+}
+
+impl <T> Clone for PersistentHistory<T> {
+	fn clone(&self) -> Self {
+		return Self {
+			head: Arc::clone(&self.head),
+			len: self.len
+		};
+	}
+}
+
+impl <T> Drop for Node<T> {
+	fn drop(&mut self) {
+		// Unlink uniquely owned nodes iteratively, as dropping a long list
+		// recursively could overflow the stack.
+		let mut previous = self.previous.take();
+
+		while let Some(node) = previous {
+			previous = match Arc::try_unwrap(node) {
+				Ok(mut node) => node.previous.take(),
+				Err(_) => None
+			};
+		}
+	}
+}
+
+/// An iterator over the values held by a [`PersistentHistory`], from the
+/// `current` value to the oldest.
+///
+/// The values are linked from the `current` value to the oldest, so the first
+/// call to `next_back` walks the remaining values to collect them.
+///
+/// This `struct` is created by [`PersistentHistory::iter`].
+#[derive(Debug)]
+pub struct PersistentHistoryIter<'a, T> {
+	// The next node to walk to, until the remaining nodes are collected into
+	// `nodes` from the newest to the oldest.
+	node: Option<&'a Arc<Node<T>>>,
+	nodes: VecDeque<&'a Node<T>>,
+	len: usize
+}
+
+impl <T> Clone for PersistentHistoryIter<'_, T> {
+	fn clone(&self) -> Self {
+		return Self {
+			node: self.node,
+			nodes: self.nodes.clone(),
+			len: self.len
+		};
+	}
+}
+
+impl <'a, T> Iterator for PersistentHistoryIter<'a, T> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let node = match self.node {
+			Some(node) => {
+				self.node = node.previous.as_ref();
+
+				node
+			},
+			None => self.nodes.pop_front()?
+		};
+
+		self.len -= 1;
+
+		return Some(&node.value);
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		return (self.len, Some(self.len));
+	}
+}
+
+impl <T> DoubleEndedIterator for PersistentHistoryIter<'_, T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		while let Some(node) = self.node {
+			self.nodes.push_back(node);
+			self.node = node.previous.as_ref();
+		}
+
+		let node = self.nodes.pop_back()?;
+
+		self.len -= 1;
+
+		return Some(&node.value);
+	}
+}
+
+impl <T> ExactSizeIterator for PersistentHistoryIter<'_, T> {}
+
+impl <T> FusedIterator for PersistentHistoryIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn update() {
+		let mut history = PersistentHistory::new("a");
+
+		assert_eq!(history.previous(), None);
+
+		history.update("b");
+		history.update("c");
+
+		assert_eq!(history.current(), &"c");
+		assert_eq!(history.previous(), Some(&"b"));
+		assert_eq!(history.nth_previous(1), Some(&"a"));
+		assert_eq!(history.nth_previous(2), None);
+		assert_eq!(history.len(), 3);
+		assert_eq!(history.iter().len(), 3);
+		assert_eq!(history.oldest(), &"a");
+	}
+
+	#[test]
+	fn iter_both_ends() {
+		let history = PersistentHistory::from_values([0, 1, 2, 3]).unwrap();
+
+		let mut iter = history.iter();
+
+		assert_eq!(iter.next(), Some(&3));
+		assert_eq!(iter.next_back(), Some(&0));
+		assert_eq!(iter.len(), 2);
+		assert_eq!(iter.clone().collect::<Vec<_>>(), vec![&2, &1]);
+		assert_eq!(iter.next_back(), Some(&1));
+		assert_eq!(iter.next(), Some(&2));
+		assert_eq!(iter.next(), None);
+		assert_eq!(iter.next_back(), None);
+
+		assert_eq!(history.iter_oldest_first().collect::<Vec<_>>(), vec![&0, &1, &2, &3]);
+	}
+
+	#[test]
+	fn update_if_changed() {
+		let mut history = PersistentHistory::new(0);
+
+		for value in [0, 1, 1, 0] {
+			history.update_if_changed(value);
+		}
+
+		assert_eq!(history.to_vec(), vec![0, 1, 0]);
+	}
+
+	#[test]
+	fn clones_share_values() {
+		let mut history = PersistentHistory::new(vec![0]);
+
+		history.update(vec![1]);
+
+		let clone = history.clone();
+
+		assert!(std::ptr::eq(history.current(), clone.current()));
+
+		history.update(vec![2]);
+
+		assert!(std::ptr::eq(history.previous().unwrap(), clone.current()));
+		assert_eq!(clone.len(), 2);
+	}
+
+	#[test]
+	fn send_to_thread() {
+		let mut history = PersistentHistory::new(0);
+
+		history.update(1);
+
+		let clone = history.clone();
+
+		let sum = std::thread::spawn(move || clone.iter().sum::<i32>()).join().unwrap();
+
+		assert_eq!(sum, 1);
+	}
+
+	#[test]
+	fn drop_long_history() {
+		let mut history = PersistentHistory::new(0);
+
+		for value in 1..1_000_000 {
+			history.update(value);
+		}
+
+		drop(history);
+	}
+
+	#[test]
+	fn export_import() {
+		assert!(PersistentHistory::<i32>::from_values([]).is_none());
+
+		let history = PersistentHistory::from_values([0, 1, 2]).unwrap();
+
+		assert_eq!(history.current(), &2);
+		assert_eq!(history.len(), 3);
+		assert_eq!(history.to_vec(), vec![0, 1, 2]);
+	}
+}
@@ -0,0 +1,294 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Tracks the values that it has held within a retention period, along with
+/// the time at which each was recorded.
+///
+/// Values recorded longer than the retention period before a new value are
+/// pruned when the new value is recorded. The `current` value is always held.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::history::TimedHistory;
+/// # use std::time::{Duration, Instant};
+/// let start = Instant::now();
+///
+/// let mut history = TimedHistory::new_at(0, Duration::from_secs(60), start);
+///
+/// history.update_at(1, start + Duration::from_secs(30));
+/// history.update_at(2, start + Duration::from_secs(90));
+///
+/// assert_eq!(history.current(), &2);
+/// assert_eq!(history.previous(), Some(&1));
+/// assert_eq!(history.len(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TimedHistory<T> {
+	entries: VecDeque<(Instant, T)>,
+	retention: Duration
+}
+
+// A `TimedHistory` always holds at least its `current` value, so it is never
+// empty.
+#[allow(clippy::len_without_is_empty)]
+impl <T> TimedHistory<T> {
+	/// Creates a new `TimedHistory` holding the `initial` value as its
+	/// `current` value, recorded now, which retains values for `retention`.
+	pub fn new(initial: T, retention: Duration) -> Self {
+		return Self::new_at(initial, retention, Instant::now());
+	}
+
+	/// Creates a new `TimedHistory` holding the `initial` value as its
+	/// `current` value, recorded at the given time, which retains values for
+	/// `retention`.
+	pub fn new_at(initial: T, retention: Duration, at: Instant) -> Self {
+		return Self {
+			entries: VecDeque::from([(at, initial)]),
+			retention
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return &self.entries.back().expect("a TimedHistory is never empty").1;
+	}
+
+	/// Gets the time at which the `current` value was recorded.
+	pub fn current_at(&self) -> Instant {
+		return self.entries.back().expect("a TimedHistory is never empty").0;
+	}
+
+	/// Gets an optional reference to the `previous` value, which is the value
+	/// held before the `current` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.nth_previous(0);
+	}
+
+	/// Gets an optional reference to the `n`th `previous` value, where `0` is
+	/// the most recent `previous` value.
+	pub fn nth_previous(&self, n: usize) -> Option<&T> {
+		let index = self.entries.len().checked_sub(n.checked_add(2)?)?;
+
+		return self.entries.get(index).map(|(_, value)| value);
+	}
+
+	/// Gets the number of held values, including the `current` value.
+	pub fn len(&self) -> usize {
+		return self.entries.len();
+	}
+
+	/// Gets a reference to the oldest held value, which is the `current` value
+	/// if no `previous` values are held.
+	pub fn oldest(&self) -> &T {
+		return &self.entries.front().expect("a TimedHistory is never empty").1;
+	}
+
+	/// Gets the period for which values are retained.
+	pub fn retention(&self) -> Duration {
+		return self.retention;
+	}
+
+	/// Returns an iterator over the held values and the times at which they
+	/// were recorded, from the `current` value to the oldest.
+	pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Instant, &T)> + ExactSizeIterator {
+		return self.entries.iter().rev().map(|(at, value)| (*at, value));
+	}
+
+	/// Sets a new `current` value, recorded now, pruning any values recorded
+	/// longer than the retention period ago.
+	pub fn update(&mut self, new: T) {
+		self.update_at(new, Instant::now());
+	}
+
+	/// Sets a new `current` value, recorded at the given time, pruning any
+	/// values recorded longer than the retention period before it.
+	pub fn update_at(&mut self, new: T, at: Instant) {
+		while self.entries.front().is_some_and(|(recorded, _)| at.saturating_duration_since(*recorded) > self.retention) {
+			self.entries.pop_front();
+		}
+
+		self.entries.push_back((at, new));
+	}
+
+	/// Sets a new `current` value, as though it were passed to
+	/// [`update`](Self::update), only if it differs from the `current` value,
+	/// so that consecutive equal values are recorded once. Returns whether
+	/// the update took place.
+	pub fn update_if_changed(&mut self, new: T) -> bool where T: PartialEq {
+		if self.current() == &new {
+			return false;
+		}
+
+		self.update(new);
+
+		return true;
+	}
+
+	/// Creates a new `TimedHistory` which retains values for `retention`, as
+	/// though the given values and the times at which they were recorded,
+	/// from the oldest to the `current` value, were passed to
+	/// [`update_at`](Self::update_at) in turn. Returns `None` if there are no
+	/// values.
+	pub fn from_values(entries: impl IntoIterator<Item = (Instant, T)>, retention: Duration) -> Option<Self> {
+		let mut entries = entries.into_iter();
+
+		let (at, initial) = entries.next()?;
+
+		let mut history = Self::new_at(initial, retention, at);
+
+		for (at, value) in entries {
+			history.update_at(value, at);
+		}
+
+		return Some(history);
+	}
+
+	/// Copies the held values and the times at which they were recorded into
+	/// a `Vec`, from the oldest to the `current` value.
+	pub fn to_vec(&self) -> Vec<(Instant, T)> where T: Clone {
+		return self.entries.iter().cloned().collect();
+	}
+
+	/// Downsamples the older values, keeping every value recorded within
+	/// `recent` of the `current` value intact, and only the newest value in
+	/// each `bucket`-long period before them, measured back from the
+	/// `current` value.
+	///
+	/// # Panics
+	///
+	/// Panics if `bucket` is zero.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::history::TimedHistory;
+	/// # use std::time::{Duration, Instant};
+	/// let start = Instant::now();
+	///
+	/// let mut history = TimedHistory::new_at(0, Duration::from_secs(3600), start);
+	///
+	/// for second in 1..=10 {
+	///     history.update_at(second, start + Duration::from_secs(second));
+	/// }
+	///
+	/// history.compact(Duration::from_secs(2), Duration::from_secs(5));
+	///
+	/// let values: Vec<_> = history.iter().map(|(_, value)| *value).collect();
+	///
+	/// assert_eq!(values, vec![10, 9, 8, 7, 5, 0]);
+	/// ```
+	pub fn compact(&mut self, recent: Duration, bucket: Duration) {
+		assert!(!bucket.is_zero(), "cannot compact a TimedHistory into zero-length buckets");
+
+		let now = self.current_at();
+		let mut last_bucket = None;
+
+		let mut kept: Vec<bool> = self.entries.iter().rev().map(|(at, _)| {
+			let age = now.saturating_duration_since(*at);
+
+			if age <= recent {
+				return true;
+			}
+
+			let this_bucket = age.as_nanos() / bucket.as_nanos();
+
+			if last_bucket == Some(this_bucket) {
+				return false;
+			}
+
+			last_bucket = Some(this_bucket);
+
+			return true;
+		}).collect();
+
+		kept.reverse();
+
+		let mut index = 0;
+
+		self.entries.retain(|_| {
+			index += 1;
+
+			return kept[index - 1];
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn prune_on_update() {
+		let start = Instant::now();
+
+		let mut history = TimedHistory::new_at("a", Duration::from_secs(10), start);
+
+		history.update_at("b", start + Duration::from_secs(5));
+		history.update_at("c", start + Duration::from_secs(10));
+
+		assert_eq!(history.len(), 3);
+
+		history.update_at("d", start + Duration::from_secs(16));
+
+		assert_eq!(history.iter().map(|(_, value)| *value).collect::<Vec<_>>(), vec!["d", "c"]);
+
+		history.update_at("e", start + Duration::from_secs(60));
+
+		assert_eq!(history.current(), &"e");
+		assert_eq!(history.current_at(), start + Duration::from_secs(60));
+		assert_eq!(history.previous(), None);
+	}
+
+	#[test]
+	fn update_now() {
+		let mut history = TimedHistory::new(0, Duration::from_secs(3600));
+
+		history.update(1);
+
+		assert_eq!(history.previous(), Some(&0));
+		assert_eq!(history.retention(), Duration::from_secs(3600));
+		assert!(history.iter().next().is_some_and(|(at, value)| value == &1 && at == history.current_at()));
+	}
+
+	#[test]
+	fn compact() {
+		let start = Instant::now();
+
+		let mut history = TimedHistory::new_at(0, Duration::from_secs(100), start);
+
+		for second in 1..=20 {
+			history.update_at(second, start + Duration::from_secs(second));
+		}
+
+		history.compact(Duration::ZERO, Duration::from_secs(10));
+
+		assert_eq!(history.iter().map(|(_, value)| *value).collect::<Vec<_>>(), vec![20, 19, 10, 0]);
+
+		history.compact(Duration::from_secs(100), Duration::from_secs(1));
+
+		assert_eq!(history.len(), 4);
+	}
+
+	#[test]
+	fn update_if_changed() {
+		let mut history = TimedHistory::new(0, Duration::from_secs(60));
+
+		assert!(!history.update_if_changed(0));
+		assert!(history.update_if_changed(1));
+		assert!(!history.update_if_changed(1));
+		assert_eq!(history.len(), 2);
+	}
+
+	#[test]
+	fn export_import() {
+		let start = Instant::now();
+
+		let entries = vec![(start, "a"), (start + Duration::from_secs(5), "b"), (start + Duration::from_secs(20), "c")];
+
+		let history = TimedHistory::from_values(entries.clone(), Duration::from_secs(10)).unwrap();
+
+		assert_eq!(history.to_vec(), entries[2..]);
+		assert_eq!(TimedHistory::<&str>::from_values([], Duration::from_secs(10)), None);
+	}
+}
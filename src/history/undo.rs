@@ -0,0 +1,266 @@
+use crate::iter::NewestFirst;
+
+/// Tracks a `current` value along with a past of values that can be restored
+/// by undoing, and a future of undone values that can be restored by redoing.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::history::UndoRedo;
+/// let mut text = UndoRedo::new(String::new());
+///
+/// text.update(String::from("a"));
+/// text.update(String::from("ab"));
+///
+/// assert!(text.undo());
+/// assert_eq!(text.current(), "a");
+///
+/// assert!(text.redo());
+/// assert_eq!(text.current(), "ab");
+///
+/// assert!(!text.redo());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UndoRedo<T> {
+	current: T,
+	past: Vec<T>,
+	future: Vec<T>
+}
+
+// An `UndoRedo` always holds at least its `current` value, so it is never
+// empty.
+#[allow(clippy::len_without_is_empty)]
+impl <T> UndoRedo<T> {
+	/// Creates a new `UndoRedo` holding the `initial` value as its `current`
+	/// value, with nothing to undo or redo.
+	pub fn new(initial: T) -> Self {
+		return Self {
+			current: initial,
+			past: Vec::new(),
+			future: Vec::new()
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return &self.current;
+	}
+
+	/// Gets an optional reference to the `previous` value, which would become
+	/// the `current` value if undone.
+	pub fn previous(&self) -> Option<&T> {
+		return self.nth_previous(0);
+	}
+
+	/// Gets an optional reference to the `n`th `previous` value, where `0` is
+	/// the most recent `previous` value.
+	pub fn nth_previous(&self, n: usize) -> Option<&T> {
+		let index = self.past.len().checked_sub(n.checked_add(1)?)?;
+
+		return self.past.get(index);
+	}
+
+	/// Gets the number of values that can be undone back to, including the
+	/// `current` value. Values that could be redone are not counted.
+	pub fn len(&self) -> usize {
+		return self.past.len() + 1;
+	}
+
+	/// Gets a reference to the oldest value that can be undone back to, which
+	/// is the `current` value if there is nothing to undo.
+	pub fn oldest(&self) -> &T {
+		return self.past.first().unwrap_or(&self.current);
+	}
+
+	/// Returns an iterator over the `current` value and the values that can be
+	/// undone back to, from the `current` value to the oldest. Values that
+	/// could be redone are not included.
+	pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+		return NewestFirst::new(&self.current, self.past.iter().rev());
+	}
+
+	/// Sets a new `current` value, which can be undone. Anything that could be
+	/// redone is discarded.
+	pub fn update(&mut self, new: T) {
+		self.past.push(std::mem::replace(&mut self.current, new));
+		self.future.clear();
+	}
+
+	/// Sets a new `current` value, as though it were passed to
+	/// [`update`](Self::update), only if it differs from the `current` value,
+	/// so that consecutive equal values are recorded once, and an equal value
+	/// does not discard anything that could be redone. Returns whether the
+	/// update took place.
+	pub fn update_if_changed(&mut self, new: T) -> bool where T: PartialEq {
+		if self.current() == &new {
+			return false;
+		}
+
+		self.update(new);
+
+		return true;
+	}
+
+	/// Restores the `previous` value as the `current` value, returning whether
+	/// there was anything to undo.
+	pub fn undo(&mut self) -> bool {
+		let Some(previous) = self.past.pop() else {
+			return false;
+		};
+
+		self.future.push(std::mem::replace(&mut self.current, previous));
+
+		return true;
+	}
+
+	/// Restores the most recently undone value as the `current` value,
+	/// returning whether there was anything to redo.
+	pub fn redo(&mut self) -> bool {
+		let Some(next) = self.future.pop() else {
+			return false;
+		};
+
+		self.past.push(std::mem::replace(&mut self.current, next));
+
+		return true;
+	}
+
+	/// Returns whether there is anything to undo.
+	pub fn can_undo(&self) -> bool {
+		return !self.past.is_empty();
+	}
+
+	/// Returns whether there is anything to redo.
+	pub fn can_redo(&self) -> bool {
+		return !self.future.is_empty();
+	}
+
+	/// Creates a new `UndoRedo` holding the given values, from the oldest to
+	/// the `current` value, each of which can be undone back to, with nothing
+	/// to redo. Returns `None` if there are no values.
+	pub fn from_values(values: impl IntoIterator<Item = T>) -> Option<Self> {
+		let mut past: Vec<T> = values.into_iter().collect();
+
+		let current = past.pop()?;
+
+		return Some(Self {
+			current,
+			past,
+			future: Vec::new()
+		});
+	}
+
+	/// Copies the values that can be undone back to and the `current` value
+	/// into a `Vec`, from the oldest to the `current` value. Values that could
+	/// be redone are not included.
+	pub fn to_vec(&self) -> Vec<T> where T: Clone {
+		let mut values = self.past.clone();
+
+		values.push(self.current.clone());
+
+		return values;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn undo_redo() {
+		let mut undo_redo = UndoRedo::new(0);
+
+		assert!(!undo_redo.can_undo());
+		assert!(!undo_redo.can_redo());
+		assert!(!undo_redo.undo());
+
+		undo_redo.update(1);
+		undo_redo.update(2);
+
+		assert!(undo_redo.undo());
+		assert!(undo_redo.undo());
+		assert!(!undo_redo.undo());
+
+		assert_eq!(undo_redo.current(), &0);
+		assert!(undo_redo.can_redo());
+
+		assert!(undo_redo.redo());
+
+		assert_eq!(undo_redo.current(), &1);
+		assert_eq!(undo_redo.previous(), Some(&0));
+	}
+
+	#[test]
+	fn update_discards_future() {
+		let mut undo_redo = UndoRedo::new(0);
+
+		undo_redo.update(1);
+		undo_redo.undo();
+		undo_redo.update(2);
+
+		assert!(!undo_redo.can_redo());
+		assert!(!undo_redo.redo());
+
+		assert_eq!(undo_redo.current(), &2);
+		assert_eq!(undo_redo.previous(), Some(&0));
+	}
+
+	#[test]
+	fn update_if_changed() {
+		let mut undo_redo = UndoRedo::new(0);
+
+		assert!(undo_redo.update_if_changed(1));
+
+		undo_redo.undo();
+
+		assert!(!undo_redo.update_if_changed(0));
+		assert!(undo_redo.can_redo());
+		assert_eq!(undo_redo.previous(), None);
+	}
+
+	#[test]
+	fn nth_previous() {
+		let mut undo_redo = UndoRedo::new(0);
+
+		undo_redo.update(1);
+		undo_redo.update(2);
+
+		assert_eq!(undo_redo.nth_previous(0), Some(&1));
+		assert_eq!(undo_redo.nth_previous(1), Some(&0));
+		assert_eq!(undo_redo.nth_previous(2), None);
+		assert_eq!(undo_redo.nth_previous(usize::MAX), None);
+
+		undo_redo.undo();
+
+		assert_eq!(undo_redo.nth_previous(0), Some(&0));
+		assert_eq!(undo_redo.nth_previous(1), None);
+	}
+
+	#[test]
+	fn iter() {
+		let mut undo_redo = UndoRedo::new(0);
+
+		undo_redo.update(1);
+		undo_redo.update(2);
+		undo_redo.undo();
+
+		assert_eq!(undo_redo.iter().collect::<Vec<_>>(), vec![&1, &0]);
+		assert_eq!(undo_redo.iter().rev().collect::<Vec<_>>(), vec![&0, &1]);
+		assert_eq!(undo_redo.iter().len(), 2);
+		assert_eq!(undo_redo.len(), 2);
+		assert_eq!(undo_redo.oldest(), &0);
+	}
+
+	#[test]
+	fn export_import() {
+		let mut undo_redo = UndoRedo::from_values([0, 1, 2]).unwrap();
+
+		assert_eq!(undo_redo.previous(), Some(&1));
+		assert!(!undo_redo.can_redo());
+
+		undo_redo.undo();
+
+		assert_eq!(undo_redo.to_vec(), vec![0, 1]);
+		assert_eq!(UndoRedo::<i32>::from_values([]), None);
+	}
+}
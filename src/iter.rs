@@ -0,0 +1,433 @@
+//! Iterators over the values held by a `CurrentPrevious`.
+
+use std::iter::FusedIterator;
+
+use crate::CurrentPrevious;
+
+/// An iterator over the values held by a `CurrentPrevious`, yielding the
+/// `previous` value, if any, and then the `current` value.
+///
+/// This `struct` is created by the `into_iter` method on `CurrentPrevious`
+/// (provided by the [`IntoIterator`] trait).
+#[derive(Clone, Debug)]
+pub struct IntoIter<T> {
+	previous: Option<T>,
+	current: Option<T>
+}
+
+impl <T> IntoIter<T> {
+	fn new(current: T, previous: Option<T>) -> Self {
+		return Self {
+			previous,
+			current: Some(current)
+		};
+	}
+}
+
+impl <T> Iterator for IntoIter<T> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		return self.previous.take().or_else(|| self.current.take());
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+
+		return (len, Some(len));
+	}
+}
+
+impl <T> DoubleEndedIterator for IntoIter<T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		return self.current.take().or_else(|| self.previous.take());
+	}
+}
+
+impl <T> ExactSizeIterator for IntoIter<T> {
+	fn len(&self) -> usize {
+		return usize::from(self.previous.is_some()) + usize::from(self.current.is_some());
+	}
+}
+
+impl <T> FusedIterator for IntoIter<T> {}
+
+/// An iterator over references to the values held by a `CurrentPrevious`,
+/// yielding the `previous` value, if any, and then the `current` value.
+///
+/// This `struct` is created by [`CurrentPrevious::iter`].
+#[derive(Clone, Debug)]
+pub struct Iter<'a, T> {
+	inner: IntoIter<&'a T>
+}
+
+impl <'a, T> Iterator for Iter<'a, T> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		return self.inner.next();
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		return self.inner.size_hint();
+	}
+}
+
+impl <T> DoubleEndedIterator for Iter<'_, T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		return self.inner.next_back();
+	}
+}
+
+impl <T> ExactSizeIterator for Iter<'_, T> {}
+
+impl <T> FusedIterator for Iter<'_, T> {}
+
+/// An iterator over mutable references to the values held by a
+/// `CurrentPrevious`, yielding the `previous` value, if any, and then the
+/// `current` value.
+///
+/// This `struct` is created by [`CurrentPrevious::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+	inner: IntoIter<&'a mut T>
+}
+
+impl <'a, T> Iterator for IterMut<'a, T> {
+	type Item = &'a mut T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		return self.inner.next();
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		return self.inner.size_hint();
+	}
+}
+
+impl <T> DoubleEndedIterator for IterMut<'_, T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		return self.inner.next_back();
+	}
+}
+
+impl <T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl <T> FusedIterator for IterMut<'_, T> {}
+
+/// An iterator over a `current` value and then the `previous` values yielded
+/// by another iterator, from the most recent to the oldest, for trackers that
+/// hold their `current` value apart from their `previous` values.
+#[derive(Clone, Debug)]
+pub(crate) struct NewestFirst<'a, T, I> {
+	current: Option<&'a T>,
+	previous: I
+}
+
+impl <'a, T, I> NewestFirst<'a, T, I> {
+	pub(crate) fn new(current: &'a T, previous: I) -> Self {
+		return Self {
+			current: Some(current),
+			previous
+		};
+	}
+}
+
+impl <'a, T, I: ExactSizeIterator<Item = &'a T>> Iterator for NewestFirst<'a, T, I> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		return self.current.take().or_else(|| self.previous.next());
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+
+		return (len, Some(len));
+	}
+}
+
+impl <'a, T, I: DoubleEndedIterator<Item = &'a T> + ExactSizeIterator> DoubleEndedIterator for NewestFirst<'a, T, I> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		return self.previous.next_back().or_else(|| self.current.take());
+	}
+}
+
+impl <'a, T, I: ExactSizeIterator<Item = &'a T>> ExactSizeIterator for NewestFirst<'a, T, I> {
+	fn len(&self) -> usize {
+		return usize::from(self.current.is_some()) + self.previous.len();
+	}
+}
+
+impl <'a, T, I: ExactSizeIterator<Item = &'a T> + FusedIterator> FusedIterator for NewestFirst<'a, T, I> {}
+
+impl <T> CurrentPrevious<T> {
+	/// Returns an iterator over the held values, yielding the `previous`
+	/// value, if any, and then the `current` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(1);
+	///
+	/// current_previous.update(2);
+	///
+	/// assert_eq!(current_previous.iter().sum::<i32>(), 3);
+	/// assert_eq!(current_previous.iter().max(), Some(&2));
+	/// ```
+	pub fn iter(&self) -> Iter<'_, T> {
+		let (current, previous) = self.pair();
+
+		return Iter {
+			inner: IntoIter::new(current, previous)
+		};
+	}
+
+	/// Returns an iterator over mutable references to the held values,
+	/// yielding the `previous` value, if any, and then the `current` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(1);
+	///
+	/// current_previous.update(2);
+	///
+	/// for value in current_previous.iter_mut() {
+	///     *value *= 10;
+	/// }
+	///
+	/// assert_eq!(current_previous.current(), &20);
+	/// assert_eq!(current_previous.previous(), Some(&10));
+	/// ```
+	pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+		let (current, previous) = self.as_mut().into_parts();
+
+		return IterMut {
+			inner: IntoIter::new(current, previous)
+		};
+	}
+
+	/// Creates a `CurrentPrevious` from an iterator, as though each item were
+	/// passed to [`update`](CurrentPrevious::update) in turn, so that the last
+	/// two items are held. Returns `None` if the iterator is empty.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let current_previous = CurrentPrevious::try_from_iter([1, 2, 3]).unwrap();
+	///
+	/// assert_eq!(current_previous.current(), &3);
+	/// assert_eq!(current_previous.previous(), Some(&2));
+	///
+	/// assert!(CurrentPrevious::<i32>::try_from_iter([]).is_none());
+	/// ```
+	pub fn try_from_iter(iter: impl IntoIterator<Item = T>) -> Option<Self> {
+		let mut iter = iter.into_iter();
+
+		let mut current_previous = Self::new(iter.next()?);
+
+		current_previous.extend(iter);
+
+		return Some(current_previous);
+	}
+}
+
+impl <T> FromIterator<T> for CurrentPrevious<T> {
+	/// Creates a `CurrentPrevious` holding the last two items of an iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the iterator is empty. See
+	/// [`CurrentPrevious::try_from_iter`] for a non-panicking alternative.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let current_previous: CurrentPrevious<i32> = (1..=5).collect();
+	///
+	/// assert_eq!(current_previous.current(), &5);
+	/// assert_eq!(current_previous.previous(), Some(&4));
+	/// ```
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		return Self::try_from_iter(iter).expect("cannot collect an empty iterator into a CurrentPrevious");
+	}
+}
+
+impl <T> Extend<T> for CurrentPrevious<T> {
+	/// Passes each item of an iterator to
+	/// [`update`](CurrentPrevious::update) in turn.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// current_previous.extend([1, 2, 3]);
+	///
+	/// assert_eq!(current_previous.current(), &3);
+	/// assert_eq!(current_previous.previous(), Some(&2));
+	/// ```
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		for value in iter {
+			self.update(value);
+		}
+	}
+}
+
+impl <T> IntoIterator for CurrentPrevious<T> {
+	type Item = T;
+	type IntoIter = IntoIter<T>;
+
+	/// Returns a consuming iterator over the held values, yielding the
+	/// `previous` value, if any, and then the `current` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(String::from("a"));
+	///
+	/// current_previous.update(String::from("b"));
+	///
+	/// let values: Vec<String> = current_previous.into_iter().collect();
+	///
+	/// assert_eq!(values, vec!["a", "b"]);
+	/// ```
+	fn into_iter(self) -> Self::IntoIter {
+		let (current, previous) = self.into_parts();
+
+		return IntoIter::new(current, previous);
+	}
+}
+
+impl <'a, T> IntoIterator for &'a CurrentPrevious<T> {
+	type Item = &'a T;
+	type IntoIter = Iter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		return self.iter();
+	}
+}
+
+impl <'a, T> IntoIterator for &'a mut CurrentPrevious<T> {
+	type Item = &'a mut T;
+	type IntoIter = IterMut<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		return self.iter_mut();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::CurrentPrevious;
+
+	#[test]
+	fn iter() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		assert_eq!(current_previous.iter().collect::<Vec<_>>(), vec![&0]);
+
+		current_previous.update(1);
+
+		assert_eq!(current_previous.iter().collect::<Vec<_>>(), vec![&0, &1]);
+	}
+
+	#[test]
+	fn iter_rev() {
+		let current_previous = CurrentPrevious::new_with_previous(1, Some(0));
+
+		assert_eq!(current_previous.iter().rev().collect::<Vec<_>>(), vec![&1, &0]);
+	}
+
+	#[test]
+	fn iter_len() {
+		let current_previous = CurrentPrevious::new_with_previous(1, Some(0));
+
+		let mut iter = current_previous.iter();
+
+		assert_eq!(iter.len(), 2);
+
+		iter.next();
+
+		assert_eq!(iter.len(), 1);
+
+		iter.next();
+
+		assert_eq!(iter.len(), 0);
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn into_iter() {
+		let current_previous = CurrentPrevious::new_with_previous(vec![1], Some(vec![0]));
+
+		assert_eq!(current_previous.into_iter().collect::<Vec<_>>(), vec![vec![0], vec![1]]);
+
+		let current_previous = CurrentPrevious::new(vec![0]);
+
+		assert_eq!(current_previous.into_iter().collect::<Vec<_>>(), vec![vec![0]]);
+	}
+
+	#[test]
+	fn try_from_iter() {
+		assert!(CurrentPrevious::try_from_iter(std::iter::empty::<i32>()).is_none());
+
+		let current_previous = CurrentPrevious::try_from_iter([0]).unwrap();
+
+		assert_eq!(current_previous.current(), &0);
+		assert_eq!(current_previous.previous(), None);
+	}
+
+	#[test]
+	fn collect() {
+		let current_previous: CurrentPrevious<char> = "abc".chars().collect();
+
+		assert_eq!(current_previous.current(), &'c');
+		assert_eq!(current_previous.previous(), Some(&'b'));
+	}
+
+	#[test]
+	#[should_panic]
+	fn collect_empty() {
+		let _: CurrentPrevious<i32> = std::iter::empty().collect();
+	}
+
+	#[test]
+	fn extend() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		current_previous.extend(std::iter::empty());
+
+		assert_eq!(current_previous.current(), &0);
+		assert_eq!(current_previous.previous(), None);
+
+		current_previous.extend([1]);
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), Some(&0));
+	}
+
+	#[test]
+	fn for_loops() {
+		let mut current_previous = CurrentPrevious::new_with_previous(1, Some(0));
+
+		for value in &mut current_previous {
+			*value += 1;
+		}
+
+		let mut values = Vec::new();
+
+		for value in &current_previous {
+			values.push(*value);
+		}
+
+		assert_eq!(values, vec![1, 2]);
+	}
+}
@@ -3,12 +3,52 @@
 //! `current_previous` contains the `CurrentPrevious` struct, which tracks the
 //! current and previous values that it has held.
 
-#[derive(Clone, Copy, Debug)]
+#![allow(clippy::needless_return)]
+
+mod current_previous_n;
+mod ring;
+
+pub mod atomic;
+pub mod change;
+pub mod clock;
+pub mod debounce;
+pub mod display;
+pub mod guard;
+pub mod history;
+pub mod iter;
+pub mod num;
+pub mod sampler;
+pub mod seqlock;
+pub mod snapshot;
+pub mod stats;
+pub mod sync;
+pub mod throttle;
+pub mod timestamped;
+pub mod tracked;
+
+pub use current_previous_n::CurrentPreviousN;
+
+/// Tracks a `current` value along with the `previous` value that it replaced.
+///
+/// `CurrentPrevious` values are ordered by their `current` values, with their
+/// `previous` values as a tiebreaker. A `previous` value of `None` is ordered
+/// before any `Some`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct CurrentPrevious<T> {
 	current: T,
 	previous: Option<T>
 }
 
+/// Names one of the two slots of a `CurrentPrevious`, for use with
+/// [`CurrentPrevious::get`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Age {
+	/// The `current` value.
+	Current,
+	/// The `previous` value.
+	Previous
+}
+
 impl <T> CurrentPrevious<T> {
 	/// Creates a new `CurrentPrevious` holding the `initial` value as its
 	/// `current` value. The `previous` value is initially `None`.
@@ -22,25 +62,98 @@ impl <T> CurrentPrevious<T> {
 	/// assert_eq!(current_previous.current(), &0);
 	/// assert_eq!(current_previous.previous(), None);
 	/// ```
-	pub fn new(initial: T) -> Self {
+	pub const fn new(initial: T) -> Self {
 		return Self {
 			current: initial,
 			previous: None
 		};
 	}
 
+	/// Creates a new `CurrentPrevious` holding the given `current` and
+	/// `previous` values.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let current_previous = CurrentPrevious::new_with_previous(1, Some(0));
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), Some(&0));
+	/// ```
+	///
+	/// Like [`new`](Self::new), this can be used in `const` and `static`
+	/// contexts.
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// static LIMITS: CurrentPrevious<u32> = CurrentPrevious::new_with_previous(10, Some(5));
+	///
+	/// assert_eq!(LIMITS.previous(), Some(&5));
+	/// ```
+	pub const fn new_with_previous(current: T, previous: Option<T>) -> Self {
+		return Self {
+			current,
+			previous
+		};
+	}
+
 	/// Gets a reference to the `current` value.
 	pub fn current(&self) -> &T {
 		return &self.current;
 	}
 
+	/// Gets a mutable reference to the `current` value.
+	///
+	/// Mutating the `current` value through this reference does not affect
+	/// the `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// *current_previous.current_mut() += 1;
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), None);
+	/// ```
+	pub fn current_mut(&mut self) -> &mut T {
+		return &mut self.current;
+	}
+
 	/// Gets an optional reference to the `previous` value.
 	pub fn previous(&self) -> Option<&T> {
 		return self.previous.as_ref();
 	}
 
+	/// Gets an optional mutable reference to the `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// assert_eq!(current_previous.previous_mut(), None);
+	///
+	/// current_previous.update(1);
+	///
+	/// if let Some(previous) = current_previous.previous_mut() {
+	///     *previous += 10;
+	/// }
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), Some(&10));
+	/// ```
+	pub fn previous_mut(&mut self) -> Option<&mut T> {
+		return self.previous.as_mut();
+	}
+
 	/// Sets a new `current` value, replacing the `previous` value with the old
-	/// `current` value.
+	/// `current` value. Returns the old `previous` value, which is no longer
+	/// held.
 	///
 	/// # Examples
 	///
@@ -51,13 +164,44 @@ impl <T> CurrentPrevious<T> {
 	/// assert_eq!(current_previous.current(), &0);
 	/// assert_eq!(current_previous.previous(), None);
 	///
-	/// current_previous.update(1);
+	/// assert_eq!(current_previous.update(1), None);
 	///
 	/// assert_eq!(current_previous.current(), &1);
 	/// assert_eq!(current_previous.previous(), Some(&0));
+	///
+	/// assert_eq!(current_previous.update(2), Some(0));
+	///
+	/// assert_eq!(current_previous.current(), &2);
+	/// assert_eq!(current_previous.previous(), Some(&1));
+	/// ```
+	pub fn update(&mut self, new: T) -> Option<T> {
+		let old = std::mem::replace(&mut self.current, new);
+
+		return self.previous.replace(old);
+	}
+
+	/// Mutates the `current` value in place, keeping a clone of the old
+	/// `current` value as the `previous` value, as though the mutated value
+	/// were passed to [`update`](Self::update). Returns the old `previous`
+	/// value, which is no longer held.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(vec![1]);
+	///
+	/// current_previous.update_in_place(|current| current.push(2));
+	///
+	/// assert_eq!(current_previous.current(), &vec![1, 2]);
+	/// assert_eq!(current_previous.previous(), Some(&vec![1]));
 	/// ```
-	pub fn update(&mut self, new: T) {
-		self.previous = Some(std::mem::replace(&mut self.current, new));
+	pub fn update_in_place(&mut self, f: impl FnOnce(&mut T)) -> Option<T> where T: Clone {
+		let old = self.current.clone();
+
+		f(&mut self.current);
+
+		return self.previous.replace(old);
 	}
 
 	/// Replaces `self` with a new `CurrentPrevious` constructed from the given
@@ -105,81 +249,1604 @@ impl <T> CurrentPrevious<T> {
 	pub fn clear_previous(&mut self) {
 		self.previous = None;
 	}
-}
-
-#[cfg(test)]
-mod tests {
-	use super::*;
-
-	#[test]
-	fn set_current() {
-		let current_previous = CurrentPrevious::new(0);
 
-		assert_eq!(current_previous.current(), &0);
-		assert_eq!(current_previous.previous(), None);
+	/// Consumes the `CurrentPrevious`, returning the `current` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(String::from("a"));
+	///
+	/// current_previous.update(String::from("b"));
+	///
+	/// assert_eq!(current_previous.into_inner(), "b");
+	/// ```
+	pub fn into_inner(self) -> T {
+		return self.current;
 	}
 
-	#[test]
-	fn set_current_twice() {
-		let mut current_previous = CurrentPrevious::new(0);
-
-		current_previous.update(1);
-
-		assert_eq!(current_previous.current(), &1);
-		assert_eq!(current_previous.previous(), Some(&0));
+	/// Consumes the `CurrentPrevious`, returning the `current` and `previous`
+	/// values as a tuple.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// assert_eq!(current_previous.into_parts(), (0, None));
+	///
+	/// current_previous.update(1);
+	///
+	/// assert_eq!(current_previous.into_parts(), (1, Some(0)));
+	/// ```
+	pub fn into_parts(self) -> (T, Option<T>) {
+		return (self.current, self.previous);
 	}
 
-	#[test]
-	fn set_current_thrice() {
-		let mut current_previous = CurrentPrevious::new(0);
+	/// Takes the `previous` value out of the `CurrentPrevious`, leaving `None`
+	/// in its place.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// current_previous.update(1);
+	///
+	/// assert_eq!(current_previous.take_previous(), Some(0));
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), None);
+	/// ```
+	pub fn take_previous(&mut self) -> Option<T> {
+		return self.previous.take();
+	}
 
-		current_previous.update(1);
+	/// Sets the `previous` value without affecting the `current` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(1);
+	///
+	/// current_previous.set_previous(0);
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), Some(&0));
+	/// ```
+	pub fn set_previous(&mut self, value: T) {
+		self.previous = Some(value);
+	}
 
-		current_previous.update(2);
+	/// Sets the `previous` value without affecting the `current` value,
+	/// returning the old `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(1);
+	///
+	/// assert_eq!(current_previous.replace_previous(0), None);
+	/// assert_eq!(current_previous.replace_previous(-1), Some(0));
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), Some(&-1));
+	/// ```
+	pub fn replace_previous(&mut self, value: T) -> Option<T> {
+		return self.previous.replace(value);
+	}
 
-		assert_eq!(current_previous.current(), &2);
-		assert_eq!(current_previous.previous(), Some(&1));
+	/// Swaps the `current` and `previous` values, returning whether a swap
+	/// took place. If the `previous` value is `None`, nothing is changed and
+	/// `false` is returned.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// assert!(!current_previous.swap());
+	///
+	/// assert_eq!(current_previous.current(), &0);
+	/// assert_eq!(current_previous.previous(), None);
+	///
+	/// current_previous.update(1);
+	///
+	/// assert!(current_previous.swap());
+	///
+	/// assert_eq!(current_previous.current(), &0);
+	/// assert_eq!(current_previous.previous(), Some(&1));
+	/// ```
+	pub fn swap(&mut self) -> bool {
+		return match self.previous.as_mut() {
+			Some(previous) => {
+				std::mem::swap(&mut self.current, previous);
+				true
+			},
+			None => false
+		};
 	}
 
-	#[test]
-	fn clone() {
-		let current_previous = CurrentPrevious::new(0);
+	/// Restores the `previous` value as the `current` value, leaving the
+	/// `previous` value as `None`. Returns the discarded `current` value, or
+	/// `None` without changing anything if there is no `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// current_previous.update(1);
+	///
+	/// assert_eq!(current_previous.rollback(), Some(1));
+	///
+	/// assert_eq!(current_previous.current(), &0);
+	/// assert_eq!(current_previous.previous(), None);
+	///
+	/// assert_eq!(current_previous.rollback(), None);
+	///
+	/// assert_eq!(current_previous.current(), &0);
+	/// ```
+	pub fn rollback(&mut self) -> Option<T> {
+		let previous = self.previous.take()?;
 
-		let mut cloned_current_previous = current_previous.clone();
+		return Some(std::mem::replace(&mut self.current, previous));
+	}
 
-		assert_eq!(current_previous.current(), &0);
-		assert_eq!(current_previous.previous(), None);
+	/// Sets the `current` value without affecting the `previous` value,
+	/// returning the old `current` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// current_previous.update(1);
+	///
+	/// assert_eq!(current_previous.replace_current(2), 1);
+	///
+	/// assert_eq!(current_previous.current(), &2);
+	/// assert_eq!(current_previous.previous(), Some(&0));
+	/// ```
+	pub fn replace_current(&mut self, new: T) -> T {
+		return std::mem::replace(&mut self.current, new);
+	}
 
-		assert_eq!(cloned_current_previous.current(), &0);
-		assert_eq!(cloned_current_previous.previous(), None);
+	/// Sets a new `current` value computed from the current `current` value,
+	/// as though it were passed to [`update`](Self::update). Returns the old
+	/// `previous` value, which is no longer held.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// current_previous.update_with(|current| current + 1);
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), Some(&0));
+	/// ```
+	pub fn update_with(&mut self, f: impl FnOnce(&T) -> T) -> Option<T> {
+		let new = f(&self.current);
 
-		cloned_current_previous.update(1);
+		return self.update(new);
+	}
 
-		assert_eq!(current_previous.current(), &0);
-		assert_eq!(current_previous.previous(), None);
+	/// Sets a new `current` value computed from both the `current` and
+	/// `previous` values, as though it were passed to
+	/// [`update`](Self::update). Returns the old `previous` value, which is no
+	/// longer held.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(2);
+	///
+	/// current_previous.update(3);
+	///
+	/// // Linear extrapolation from the last two values.
+	/// current_previous.update_from(|current, previous| match previous {
+	///     Some(previous) => current + (current - previous),
+	///     None => *current
+	/// });
+	///
+	/// assert_eq!(current_previous.current(), &4);
+	/// assert_eq!(current_previous.previous(), Some(&3));
+	/// ```
+	pub fn update_from(&mut self, f: impl FnOnce(&T, Option<&T>) -> T) -> Option<T> {
+		let new = f(&self.current, self.previous.as_ref());
 
-		assert_eq!(cloned_current_previous.current(), &1);
-		assert_eq!(cloned_current_previous.previous(), Some(&0));
+		return self.update(new);
 	}
 
-	#[test]
-	fn debug_print() {
-		let mut current_previous = CurrentPrevious::new(0);
-
-		assert_eq!(format!("{current_previous:?}"), "CurrentPrevious { current: 0, previous: None }");
+	/// Attempts to set a new `current` value computed from the current
+	/// `current` value. If the computation fails, the error is returned and
+	/// the `CurrentPrevious` is left unchanged.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// assert_eq!(current_previous.try_update_with(|_| "1".parse::<i32>()), Ok(()));
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), Some(&0));
+	///
+	/// assert!(current_previous.try_update_with(|_| "one".parse::<i32>()).is_err());
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), Some(&0));
+	/// ```
+	pub fn try_update_with<E>(&mut self, f: impl FnOnce(&T) -> Result<T, E>) -> Result<(), E> {
+		let new = f(&self.current)?;
 
-		current_previous.update(1);
+		self.update(new);
 
-		assert_eq!(format!("{current_previous:?}"), "CurrentPrevious { current: 1, previous: Some(0) }");
+		return Ok(());
 	}
 
-	#[test]
-	fn reset() {
+	/// Sets a new `current` value, as though it were passed to
+	/// [`update`](Self::update), only if the predicate over the `current` and
+	/// `new` values returns `true`. Returns whether the update took place.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(1);
+	///
+	/// assert!(!current_previous.update_if(0, |current, new| new > current));
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), None);
+	///
+	/// assert!(current_previous.update_if(2, |current, new| new > current));
+	///
+	/// assert_eq!(current_previous.current(), &2);
+	/// assert_eq!(current_previous.previous(), Some(&1));
+	/// ```
+	pub fn update_if(&mut self, new: T, pred: impl FnOnce(&T, &T) -> bool) -> bool {
+		if !pred(&self.current, &new) {
+			return false;
+		}
+
+		self.update(new);
+
+		return true;
+	}
+
+	/// Sets a new `current` value, as though it were passed to
+	/// [`update`](Self::update), only if it differs from the `current` value.
+	/// Returns whether the update took place.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// assert!(current_previous.update_if_changed(1));
+	/// assert!(!current_previous.update_if_changed(1));
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), Some(&0));
+	/// ```
+	pub fn update_if_changed(&mut self, new: T) -> bool where T: PartialEq {
+		return self.update_if(new, |current, new| current != new);
+	}
+
+	/// Maps a `CurrentPrevious<T>` to a `CurrentPrevious<U>` by applying a
+	/// function to the `previous` value, if any, and then to the `current`
+	/// value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(String::from("a"));
+	///
+	/// current_previous.update(String::from("bc"));
+	///
+	/// let lengths = current_previous.map(|value| value.len());
+	///
+	/// assert_eq!(lengths.current(), &2);
+	/// assert_eq!(lengths.previous(), Some(&1));
+	/// ```
+	pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> CurrentPrevious<U> {
+		let previous = self.previous.map(&mut f);
+
+		return CurrentPrevious::new_with_previous(f(self.current), previous);
+	}
+
+	/// Applies a function to the `current` value, leaving the `previous` value
+	/// untouched.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(1);
+	///
+	/// current_previous.update(2);
+	///
+	/// let current_previous = current_previous.map_current(|current| current * 10);
+	///
+	/// assert_eq!(current_previous.current(), &20);
+	/// assert_eq!(current_previous.previous(), Some(&1));
+	/// ```
+	pub fn map_current(self, f: impl FnOnce(T) -> T) -> Self {
+		return Self {
+			current: f(self.current),
+			..self
+		};
+	}
+
+	/// Applies a function to the `previous` value, if any, leaving the
+	/// `current` value untouched.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(1);
+	///
+	/// current_previous.update(2);
+	///
+	/// let current_previous = current_previous.map_previous(|previous| previous * 10);
+	///
+	/// assert_eq!(current_previous.current(), &2);
+	/// assert_eq!(current_previous.previous(), Some(&10));
+	/// ```
+	pub fn map_previous(self, f: impl FnOnce(T) -> T) -> Self {
+		return Self {
+			previous: self.previous.map(f),
+			..self
+		};
+	}
+
+	/// Converts from `&CurrentPrevious<T>` to `CurrentPrevious<&T>`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(String::from("a"));
+	///
+	/// current_previous.update(String::from("bc"));
+	///
+	/// let lengths = current_previous.as_ref().map(|value| value.len());
+	///
+	/// assert_eq!(lengths.current(), &2);
+	/// assert_eq!(lengths.previous(), Some(&1));
+	///
+	/// assert_eq!(current_previous.current(), "bc");
+	/// ```
+	pub fn as_ref(&self) -> CurrentPrevious<&T> {
+		return CurrentPrevious::new_with_previous(&self.current, self.previous.as_ref());
+	}
+
+	/// Converts from `&mut CurrentPrevious<T>` to `CurrentPrevious<&mut T>`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(1);
+	///
+	/// current_previous.update(2);
+	///
+	/// current_previous.as_mut().map(|value| *value *= 10);
+	///
+	/// assert_eq!(current_previous.current(), &20);
+	/// assert_eq!(current_previous.previous(), Some(&10));
+	/// ```
+	pub fn as_mut(&mut self) -> CurrentPrevious<&mut T> {
+		return CurrentPrevious::new_with_previous(&mut self.current, self.previous.as_mut());
+	}
+
+	/// Zips `self` with another `CurrentPrevious`, pairing up their `current`
+	/// values and their `previous` values. The zipped `previous` value is
+	/// `None` unless both `previous` values are present.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut x = CurrentPrevious::new(0);
+	/// let mut y = CurrentPrevious::new(10);
+	///
+	/// x.update(1);
+	///
+	/// let position = x.zip(y);
+	///
+	/// assert_eq!(position.current(), &(1, 10));
+	/// assert_eq!(position.previous(), None);
+	///
+	/// y.update(11);
+	///
+	/// let position = x.zip(y);
+	///
+	/// assert_eq!(position.current(), &(1, 11));
+	/// assert_eq!(position.previous(), Some(&(0, 10)));
+	/// ```
+	pub fn zip<U>(self, other: CurrentPrevious<U>) -> CurrentPrevious<(T, U)> {
+		return CurrentPrevious::new_with_previous(
+			(self.current, other.current),
+			self.previous.zip(other.previous)
+		);
+	}
+
+	/// Gets references to both the `current` and `previous` values at once.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// current_previous.update(1);
+	///
+	/// let message = match current_previous.pair() {
+	///     (current, Some(previous)) => format!("{previous} -> {current}"),
+	///     (current, None) => format!("{current}")
+	/// };
+	///
+	/// assert_eq!(message, "0 -> 1");
+	/// ```
+	pub fn pair(&self) -> (&T, Option<&T>) {
+		return (&self.current, self.previous.as_ref());
+	}
+
+	/// Returns whether there is a `previous` value that differs from the
+	/// `current` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// assert!(!current_previous.changed());
+	///
+	/// current_previous.update(1);
+	///
+	/// assert!(current_previous.changed());
+	///
+	/// current_previous.update(1);
+	///
+	/// assert!(!current_previous.changed());
+	/// ```
+	pub fn changed(&self) -> bool where T: PartialEq {
+		return self.previous.as_ref().is_some_and(|previous| previous != &self.current);
+	}
+
+	/// Gets a reference to the smaller of the `current` and `previous` values,
+	/// or the `current` value if there is no `previous` value. If the values
+	/// are equal, the `current` value is returned.
+	///
+	/// This is not named `min`, as that would be shadowed by [`Ord::min`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(5);
+	///
+	/// assert_eq!(current_previous.min_value(), &5);
+	///
+	/// current_previous.update(7);
+	///
+	/// assert_eq!(current_previous.min_value(), &5);
+	/// ```
+	pub fn min_value(&self) -> &T where T: Ord {
+		return match self.pair() {
+			(current, Some(previous)) if previous < current => previous,
+			(current, _) => current
+		};
+	}
+
+	/// Gets a reference to the larger of the `current` and `previous` values,
+	/// or the `current` value if there is no `previous` value. If the values
+	/// are equal, the `current` value is returned.
+	///
+	/// This is not named `max`, as that would be shadowed by [`Ord::max`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(5);
+	///
+	/// assert_eq!(current_previous.max_value(), &5);
+	///
+	/// current_previous.update(3);
+	///
+	/// assert_eq!(current_previous.max_value(), &5);
+	/// ```
+	pub fn max_value(&self) -> &T where T: Ord {
+		return match self.pair() {
+			(current, Some(previous)) if previous > current => previous,
+			(current, _) => current
+		};
+	}
+
+	/// Returns whether there is no `previous` value, as is the case for a
+	/// newly created `CurrentPrevious`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// assert!(current_previous.is_initial());
+	///
+	/// current_previous.update(1);
+	///
+	/// assert!(!current_previous.is_initial());
+	/// ```
+	pub fn is_initial(&self) -> bool {
+		return self.previous.is_none();
+	}
+
+	/// Gets a reference to the `previous` value, or to the `current` value if
+	/// there is no `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// assert_eq!(current_previous.previous_or_current(), &0);
+	///
+	/// current_previous.update(1);
+	///
+	/// assert_eq!(current_previous.previous_or_current(), &0);
+	///
+	/// current_previous.update(2);
+	///
+	/// assert_eq!(current_previous.previous_or_current(), &1);
+	/// ```
+	pub fn previous_or_current(&self) -> &T {
+		return self.previous.as_ref().unwrap_or(&self.current);
+	}
+
+	/// Gets a reference to the `previous` value, or to `default` if there is
+	/// no `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(1);
+	///
+	/// assert_eq!(current_previous.previous_or(&0), &0);
+	///
+	/// current_previous.update(2);
+	///
+	/// assert_eq!(current_previous.previous_or(&0), &1);
+	/// ```
+	pub fn previous_or<'a>(&'a self, default: &'a T) -> &'a T {
+		return self.previous.as_ref().unwrap_or(default);
+	}
+
+	/// Takes the `current` value out of the `CurrentPrevious`, as though
+	/// `T::default()` were passed to [`update`](Self::update). A clone of the
+	/// taken value is kept as the `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(vec![1, 2]);
+	///
+	/// assert_eq!(current_previous.take(), vec![1, 2]);
+	///
+	/// assert_eq!(current_previous.current(), &vec![]);
+	/// assert_eq!(current_previous.previous(), Some(&vec![1, 2]));
+	/// ```
+	pub fn take(&mut self) -> T where T: Default + Clone {
+		let taken = self.current.clone();
+
+		self.update(T::default());
+
+		return taken;
+	}
+
+	/// Gets a reference to the value of the given [`Age`], or `None` if there
+	/// is no such value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::{Age, CurrentPrevious};
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// assert_eq!(current_previous.get(Age::Current), Some(&0));
+	/// assert_eq!(current_previous.get(Age::Previous), None);
+	///
+	/// current_previous.update(1);
+	///
+	/// assert_eq!(current_previous.get(Age::Current), Some(&1));
+	/// assert_eq!(current_previous.get(Age::Previous), Some(&0));
+	/// ```
+	pub fn get(&self, age: Age) -> Option<&T> {
+		return match age {
+			Age::Current => Some(&self.current),
+			Age::Previous => self.previous.as_ref()
+		};
+	}
+
+	/// Acknowledges the latest change by taking the `previous` value out of the
+	/// `CurrentPrevious`, so that it is [initial](Self::is_initial) until the
+	/// next update.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// current_previous.update(1);
+	///
+	/// if current_previous.changed() {
+	///     assert_eq!(current_previous.commit(), Some(0));
+	/// }
+	///
+	/// assert!(current_previous.is_initial());
+	/// assert_eq!(current_previous.commit(), None);
+	/// ```
+	pub fn commit(&mut self) -> Option<T> {
+		return self.take_previous();
+	}
+}
+
+impl <T> From<T> for CurrentPrevious<T> {
+	/// Creates a new `CurrentPrevious` holding the given value as its
+	/// `current` value, equivalent to [`CurrentPrevious::new`].
+	fn from(value: T) -> Self {
+		return Self::new(value);
+	}
+}
+
+impl <T> From<(T, Option<T>)> for CurrentPrevious<T> {
+	/// Creates a new `CurrentPrevious` from a `(current, previous)` tuple,
+	/// equivalent to [`CurrentPrevious::new_with_previous`].
+	fn from((current, previous): (T, Option<T>)) -> Self {
+		return Self::new_with_previous(current, previous);
+	}
+}
+
+impl <T> From<CurrentPrevious<T>> for (T, Option<T>) {
+	/// Converts a `CurrentPrevious` into a `(current, previous)` tuple,
+	/// equivalent to [`CurrentPrevious::into_parts`].
+	fn from(current_previous: CurrentPrevious<T>) -> Self {
+		return current_previous.into_parts();
+	}
+}
+
+impl <A, B> CurrentPrevious<(A, B)> {
+	/// Unzips a `CurrentPrevious` of pairs into a pair of `CurrentPrevious`
+	/// values.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let position = CurrentPrevious::new_with_previous((1, 11), Some((0, 10)));
+	///
+	/// let (x, y) = position.unzip();
+	///
+	/// assert_eq!(x.current(), &1);
+	/// assert_eq!(x.previous(), Some(&0));
+	///
+	/// assert_eq!(y.current(), &11);
+	/// assert_eq!(y.previous(), Some(&10));
+	/// ```
+	pub fn unzip(self) -> (CurrentPrevious<A>, CurrentPrevious<B>) {
+		let (current_a, current_b) = self.current;
+		let (previous_a, previous_b) = self.previous.unzip();
+
+		return (
+			CurrentPrevious::new_with_previous(current_a, previous_a),
+			CurrentPrevious::new_with_previous(current_b, previous_b)
+		);
+	}
+}
+
+impl <T> CurrentPrevious<Option<T>> {
+	/// Transposes a `CurrentPrevious` of `Option`s into an `Option` of a
+	/// `CurrentPrevious`.
+	///
+	/// If the `current` value is `None`, `None` is returned. Otherwise, a
+	/// `previous` value of `Some(None)` is flattened into `None`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let current_previous = CurrentPrevious::new_with_previous(Some(1), Some(Some(0)));
+	///
+	/// assert_eq!(current_previous.transpose().map(|cp| cp.into_parts()), Some((1, Some(0))));
+	///
+	/// let current_previous = CurrentPrevious::new_with_previous(Some(1), Some(None));
+	///
+	/// assert_eq!(current_previous.transpose().map(|cp| cp.into_parts()), Some((1, None)));
+	///
+	/// let current_previous = CurrentPrevious::new_with_previous(None, Some(Some(0)));
+	///
+	/// assert!(current_previous.transpose().is_none());
+	/// ```
+	pub fn transpose(self) -> Option<CurrentPrevious<T>> {
+		return Some(CurrentPrevious::new_with_previous(self.current?, self.previous.flatten()));
+	}
+}
+
+impl <T, E> CurrentPrevious<Result<T, E>> {
+	/// Transposes a `CurrentPrevious` of `Result`s into a `Result` of a
+	/// `CurrentPrevious`.
+	///
+	/// If the `current` value is an `Err`, it is returned. Otherwise, if the
+	/// `previous` value is an `Err`, it is returned.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let current_previous: CurrentPrevious<Result<i32, &str>> = CurrentPrevious::new_with_previous(Ok(1), Some(Ok(0)));
+	///
+	/// assert_eq!(current_previous.transpose().map(|cp| cp.into_parts()), Ok((1, Some(0))));
+	///
+	/// let current_previous: CurrentPrevious<Result<i32, &str>> = CurrentPrevious::new_with_previous(Ok(1), Some(Err("bad")));
+	///
+	/// assert_eq!(current_previous.transpose().map(|cp| cp.into_parts()), Err("bad"));
+	/// ```
+	pub fn transpose(self) -> Result<CurrentPrevious<T>, E> {
+		let current = self.current?;
+		let previous = self.previous.transpose()?;
+
+		return Ok(CurrentPrevious::new_with_previous(current, previous));
+	}
+}
+
+impl <T> CurrentPrevious<&T> {
+	/// Maps a `CurrentPrevious<&T>` to a `CurrentPrevious<T>` by cloning both
+	/// values.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let current_previous = CurrentPrevious::new_with_previous(String::from("b"), Some(String::from("a")));
+	///
+	/// let cloned: CurrentPrevious<String> = current_previous.as_ref().cloned();
+	///
+	/// assert_eq!(cloned.current(), "b");
+	/// assert_eq!(cloned.previous().map(String::as_str), Some("a"));
+	/// ```
+	pub fn cloned(self) -> CurrentPrevious<T> where T: Clone {
+		return self.map(T::clone);
+	}
+
+	/// Maps a `CurrentPrevious<&T>` to a `CurrentPrevious<T>` by copying both
+	/// values.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let current_previous = CurrentPrevious::new_with_previous(1, Some(0));
+	///
+	/// let copied: CurrentPrevious<i32> = current_previous.as_ref().copied();
+	///
+	/// assert_eq!(copied.current(), &1);
+	/// assert_eq!(copied.previous(), Some(&0));
+	/// ```
+	pub fn copied(self) -> CurrentPrevious<T> where T: Copy {
+		return self.map(|value| *value);
+	}
+}
+
+impl <T> std::ops::Deref for CurrentPrevious<T> {
+	type Target = T;
+
+	/// Dereferences to the `current` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(String::from("a"));
+	///
+	/// current_previous.update(String::from("abc"));
+	///
+	/// assert_eq!(current_previous.len(), 3);
+	/// assert!(current_previous.starts_with("ab"));
+	/// ```
+	fn deref(&self) -> &Self::Target {
+		return self.current();
+	}
+}
+
+impl <T> AsRef<T> for CurrentPrevious<T> {
+	/// Gets a reference to the `current` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// # use std::collections::HashMap;
+	/// fn lookup(scores: &HashMap<String, i32>, key: impl AsRef<String>) -> Option<i32> {
+	///     return scores.get(key.as_ref()).copied();
+	/// }
+	///
+	/// let scores = HashMap::from([(String::from("alice"), 1), (String::from("bob"), 2)]);
+	///
+	/// let mut player = CurrentPrevious::new(String::from("alice"));
+	///
+	/// player.update(String::from("bob"));
+	///
+	/// assert_eq!(lookup(&scores, player), Some(2));
+	/// ```
+	fn as_ref(&self) -> &T {
+		return self.current();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn set_current() {
+		let current_previous = CurrentPrevious::new(0);
+
+		assert_eq!(current_previous.current(), &0);
+		assert_eq!(current_previous.previous(), None);
+	}
+
+	#[test]
+	fn set_current_twice() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		current_previous.update(1);
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), Some(&0));
+	}
+
+	#[test]
+	fn set_current_thrice() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		current_previous.update(1);
+
+		current_previous.update(2);
+
+		assert_eq!(current_previous.current(), &2);
+		assert_eq!(current_previous.previous(), Some(&1));
+	}
+
+	#[test]
+	#[allow(clippy::clone_on_copy)]
+	fn clone() {
+		let current_previous = CurrentPrevious::new(0);
+
+		let mut cloned_current_previous = current_previous.clone();
+
+		assert_eq!(current_previous.current(), &0);
+		assert_eq!(current_previous.previous(), None);
+
+		assert_eq!(cloned_current_previous.current(), &0);
+		assert_eq!(cloned_current_previous.previous(), None);
+
+		cloned_current_previous.update(1);
+
+		assert_eq!(current_previous.current(), &0);
+		assert_eq!(current_previous.previous(), None);
+
+		assert_eq!(cloned_current_previous.current(), &1);
+		assert_eq!(cloned_current_previous.previous(), Some(&0));
+	}
+
+	#[test]
+	fn debug_print() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		assert_eq!(format!("{current_previous:?}"), "CurrentPrevious { current: 0, previous: None }");
+
+		current_previous.update(1);
+
+		assert_eq!(format!("{current_previous:?}"), "CurrentPrevious { current: 1, previous: Some(0) }");
+	}
+
+	#[test]
+	fn reset() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		current_previous.reset(1);
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), None);
+	}
+
+	#[test]
+	fn current_mut() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		current_previous.update(1);
+
+		*current_previous.current_mut() += 1;
+
+		assert_eq!(current_previous.current(), &2);
+		assert_eq!(current_previous.previous(), Some(&0));
+	}
+
+	#[test]
+	fn previous_mut() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		assert_eq!(current_previous.previous_mut(), None);
+
+		current_previous.update(1);
+
+		*current_previous.previous_mut().unwrap() -= 1;
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), Some(&-1));
+	}
+
+	#[test]
+	fn into_inner() {
+		let mut current_previous = CurrentPrevious::new(vec![0]);
+
+		current_previous.update(vec![1]);
+
+		assert_eq!(current_previous.into_inner(), vec![1]);
+	}
+
+	#[test]
+	fn into_parts() {
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		current_previous.update(String::from("b"));
+
+		let (current, previous) = current_previous.into_parts();
+
+		assert_eq!(current, "b");
+		assert_eq!(previous.as_deref(), Some("a"));
+	}
+
+	#[test]
+	fn take_previous() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		assert_eq!(current_previous.take_previous(), None);
+
+		current_previous.update(1);
+
+		assert_eq!(current_previous.take_previous(), Some(0));
+		assert_eq!(current_previous.take_previous(), None);
+
+		assert_eq!(current_previous.current(), &1);
+	}
+
+	#[test]
+	fn set_previous() {
+		let mut current_previous = CurrentPrevious::new(1);
+
+		current_previous.set_previous(0);
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), Some(&0));
+
+		current_previous.update(2);
+
+		assert_eq!(current_previous.previous(), Some(&1));
+	}
+
+	#[test]
+	fn replace_previous() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		current_previous.update(1);
+
+		assert_eq!(current_previous.replace_previous(5), Some(0));
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), Some(&5));
+	}
+
+	#[test]
+	fn new_with_previous() {
+		let current_previous = CurrentPrevious::new_with_previous(1, Some(0));
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), Some(&0));
+
+		let current_previous = CurrentPrevious::new_with_previous(1, None);
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), None);
+	}
+
+	#[test]
+	fn from_value() {
+		let current_previous = CurrentPrevious::from(0);
+
+		assert_eq!(current_previous.current(), &0);
+		assert_eq!(current_previous.previous(), None);
+	}
+
+	#[test]
+	fn from_tuple() {
+		let current_previous: CurrentPrevious<i32> = (1, Some(0)).into();
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), Some(&0));
+	}
+
+	#[test]
+	fn into_tuple() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		current_previous.update(1);
+
+		let parts: (i32, Option<i32>) = current_previous.into();
+
+		assert_eq!(parts, (1, Some(0)));
+	}
+
+	#[test]
+	fn swap() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		assert!(!current_previous.swap());
+
+		assert_eq!(current_previous.current(), &0);
+		assert_eq!(current_previous.previous(), None);
+
+		current_previous.update(1);
+
+		assert!(current_previous.swap());
+
+		assert_eq!(current_previous.current(), &0);
+		assert_eq!(current_previous.previous(), Some(&1));
+
+		assert!(current_previous.swap());
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), Some(&0));
+	}
+
+	#[test]
+	fn rollback() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		assert_eq!(current_previous.rollback(), None);
+
+		current_previous.update(1);
+		current_previous.update(2);
+
+		assert_eq!(current_previous.rollback(), Some(2));
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), None);
+
+		assert_eq!(current_previous.rollback(), None);
+
+		assert_eq!(current_previous.current(), &1);
+	}
+
+	#[test]
+	fn update_returns_evicted() {
 		let mut current_previous = CurrentPrevious::new(0);
 
-		current_previous.reset(1);
+		assert_eq!(current_previous.update(1), None);
+		assert_eq!(current_previous.update(2), Some(0));
+		assert_eq!(current_previous.update(3), Some(1));
+
+		current_previous.clear_previous();
+
+		assert_eq!(current_previous.update(4), None);
+	}
+
+	#[test]
+	fn replace_current() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		assert_eq!(current_previous.replace_current(1), 0);
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), None);
+	}
+
+	#[test]
+	fn update_with() {
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		assert_eq!(current_previous.update_with(|current| format!("{current}b")), None);
+		assert_eq!(current_previous.update_with(|current| format!("{current}c")), Some(String::from("a")));
+
+		assert_eq!(current_previous.current(), "abc");
+		assert_eq!(current_previous.previous().map(String::as_str), Some("ab"));
+	}
+
+	#[test]
+	fn update_from() {
+		let mut current_previous = CurrentPrevious::new(1);
+
+		current_previous.update_from(|current, previous| current + previous.copied().unwrap_or(0));
+
+		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), Some(&1));
+
+		current_previous.update_from(|current, previous| current + previous.copied().unwrap_or(0));
+		current_previous.update_from(|current, previous| current + previous.copied().unwrap_or(0));
+
+		assert_eq!(current_previous.current(), &3);
+		assert_eq!(current_previous.previous(), Some(&2));
+	}
+
+	#[test]
+	fn try_update_with() {
+		let mut current_previous = CurrentPrevious::new(250u8);
+
+		assert_eq!(current_previous.try_update_with(|current| current.checked_add(5).ok_or("overflow")), Ok(()));
+
+		assert_eq!(current_previous.current(), &255);
+		assert_eq!(current_previous.previous(), Some(&250));
+
+		assert_eq!(current_previous.try_update_with(|current| current.checked_add(5).ok_or("overflow")), Err("overflow"));
+
+		assert_eq!(current_previous.current(), &255);
+		assert_eq!(current_previous.previous(), Some(&250));
+	}
+
+	#[test]
+	fn update_if() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		for new in [1, 3, 2, 5, 4] {
+			current_previous.update_if(new, |current, new| new > current);
+		}
+
+		assert_eq!(current_previous.current(), &5);
+		assert_eq!(current_previous.previous(), Some(&3));
+	}
+
+	#[test]
+	fn update_if_changed() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		for new in [0, 1, 1, 1, 2, 2] {
+			current_previous.update_if_changed(new);
+		}
+
+		assert_eq!(current_previous.current(), &2);
+		assert_eq!(current_previous.previous(), Some(&1));
+	}
+
+	#[test]
+	fn map() {
+		let current_previous = CurrentPrevious::new(1).map(|value| value * 2);
+
+		assert_eq!(current_previous.current(), &2);
+		assert_eq!(current_previous.previous(), None);
+
+		let mut order = Vec::new();
+
+		let mut current_previous = CurrentPrevious::new(1);
+
+		current_previous.update(2);
+
+		let current_previous = current_previous.map(|value| {
+			order.push(value);
+			value.to_string()
+		});
+
+		assert_eq!(current_previous.current(), "2");
+		assert_eq!(current_previous.previous().map(String::as_str), Some("1"));
+		assert_eq!(order, vec![1, 2]);
+	}
+
+	#[test]
+	fn map_current() {
+		let current_previous = CurrentPrevious::new(String::from("a")).map_current(|current| current.to_uppercase());
+
+		assert_eq!(current_previous.current(), "A");
+		assert_eq!(current_previous.previous(), None);
+	}
+
+	#[test]
+	fn map_previous() {
+		let current_previous = CurrentPrevious::new(0).map_previous(|_| panic!("there is no previous value"));
+
+		assert_eq!(current_previous.current(), &0);
+		assert_eq!(current_previous.previous(), None);
+
+		let current_previous = CurrentPrevious::new_with_previous(1, Some(0)).map_previous(|previous| previous - 1);
 
 		assert_eq!(current_previous.current(), &1);
+		assert_eq!(current_previous.previous(), Some(&-1));
+	}
+
+	#[test]
+	fn as_ref() {
+		let current_previous = CurrentPrevious::new_with_previous(vec![1], Some(vec![0]));
+
+		let borrowed = current_previous.as_ref();
+
+		assert_eq!(borrowed.current(), &&vec![1]);
+		assert_eq!(borrowed.previous(), Some(&&vec![0]));
+	}
+
+	#[test]
+	fn as_mut() {
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		current_previous.as_mut().map(|value| value.push('!'));
+
+		assert_eq!(current_previous.current(), "a!");
+		assert_eq!(current_previous.previous(), None);
+
+		current_previous.update(String::from("b"));
+
+		let borrowed = current_previous.as_mut();
+
+		borrowed.into_inner().push('?');
+
+		assert_eq!(current_previous.current(), "b?");
+		assert_eq!(current_previous.previous().map(String::as_str), Some("a!"));
+	}
+
+	#[test]
+	fn zip() {
+		let a = CurrentPrevious::new_with_previous(1, Some(0));
+		let b = CurrentPrevious::new_with_previous("b", Some("a"));
+
+		let zipped = a.zip(b);
+
+		assert_eq!(zipped.current(), &(1, "b"));
+		assert_eq!(zipped.previous(), Some(&(0, "a")));
+
+		let zipped = a.zip(CurrentPrevious::new("c"));
+
+		assert_eq!(zipped.current(), &(1, "c"));
+		assert_eq!(zipped.previous(), None);
+	}
+
+	#[test]
+	fn unzip() {
+		let (a, b) = CurrentPrevious::new((1, "b")).unzip();
+
+		assert_eq!(a.current(), &1);
+		assert_eq!(a.previous(), None);
+
+		assert_eq!(b.current(), &"b");
+		assert_eq!(b.previous(), None);
+
+		let zipped = CurrentPrevious::new_with_previous(1, Some(0)).zip(CurrentPrevious::new_with_previous("b", Some("a")));
+
+		let (a, b) = zipped.unzip();
+
+		assert_eq!(a.previous(), Some(&0));
+		assert_eq!(b.previous(), Some(&"a"));
+	}
+
+	#[test]
+	fn transpose_option() {
+		let transposed = CurrentPrevious::new(Some(0)).transpose().unwrap();
+
+		assert_eq!(transposed.current(), &0);
+		assert_eq!(transposed.previous(), None);
+
+		let mut current_previous = CurrentPrevious::new(None);
+
+		assert!(current_previous.transpose().is_none());
+
+		current_previous.update(Some(1));
+
+		let transposed = current_previous.transpose().unwrap();
+
+		assert_eq!(transposed.current(), &1);
+		assert_eq!(transposed.previous(), None);
+	}
+
+	#[test]
+	fn transpose_result() {
+		let current_previous: CurrentPrevious<Result<i32, &str>> = CurrentPrevious::new(Ok(0));
+
+		let transposed = current_previous.transpose().unwrap();
+
+		assert_eq!(transposed.current(), &0);
+		assert_eq!(transposed.previous(), None);
+
+		let current_previous: CurrentPrevious<Result<i32, &str>> = CurrentPrevious::new_with_previous(Err("current"), Some(Err("previous")));
+
+		assert_eq!(current_previous.transpose().unwrap_err(), "current");
+	}
+
+	#[test]
+	fn cloned() {
+		let current_previous = CurrentPrevious::new(vec![0]);
+
+		let mut cloned = current_previous.as_ref().cloned();
+
+		cloned.update(vec![1]);
+
+		assert_eq!(current_previous.current(), &vec![0]);
+		assert_eq!(cloned.current(), &vec![1]);
+		assert_eq!(cloned.previous(), Some(&vec![0]));
+	}
+
+	#[test]
+	fn copied() {
+		let values = [0, 1];
+
+		let copied = CurrentPrevious::new_with_previous(&values[1], Some(&values[0])).copied();
+
+		assert_eq!(copied.current(), &1);
+		assert_eq!(copied.previous(), Some(&0));
+	}
+
+	#[test]
+	fn pair() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		assert_eq!(current_previous.pair(), (&0, None));
+
+		current_previous.update(1);
+
+		assert_eq!(current_previous.pair(), (&1, Some(&0)));
+	}
+
+	#[test]
+	fn default() {
+		let current_previous: CurrentPrevious<i32> = CurrentPrevious::default();
+
+		assert_eq!(current_previous.current(), &0);
+		assert_eq!(current_previous.previous(), None);
+	}
+
+	#[test]
+	fn mem_take() {
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		current_previous.update(String::from("b"));
+
+		let taken = std::mem::take(&mut current_previous);
+
+		assert_eq!(taken.current(), "b");
+		assert_eq!(current_previous.current(), "");
 		assert_eq!(current_previous.previous(), None);
 	}
+
+	#[test]
+	fn eq() {
+		let mut a = CurrentPrevious::new(0);
+		let mut b = CurrentPrevious::new(0);
+
+		assert_eq!(a, b);
+
+		a.update(1);
+
+		assert_ne!(a, b);
+
+		b.update(1);
+
+		assert_eq!(a, b);
+
+		assert_ne!(a, CurrentPrevious::new(1));
+	}
+
+	#[test]
+	fn hash_set() {
+		let mut transitions = std::collections::HashSet::new();
+
+		transitions.insert(CurrentPrevious::new_with_previous(1, Some(0)));
+		transitions.insert(CurrentPrevious::new_with_previous(2, Some(1)));
+		transitions.insert(CurrentPrevious::new_with_previous(1, Some(0)));
+		transitions.insert(CurrentPrevious::new(1));
+
+		assert_eq!(transitions.len(), 3);
+		assert!(transitions.contains(&CurrentPrevious::new_with_previous(2, Some(1))));
+	}
+
+	#[test]
+	fn ord() {
+		assert!(CurrentPrevious::new(0) < CurrentPrevious::new(1));
+		assert!(CurrentPrevious::new_with_previous(0, Some(5)) < CurrentPrevious::new(1));
+		assert!(CurrentPrevious::new(1) < CurrentPrevious::new_with_previous(1, Some(0)));
+		assert!(CurrentPrevious::new_with_previous(1, Some(0)) < CurrentPrevious::new_with_previous(1, Some(2)));
+	}
+
+	#[test]
+	fn binary_heap() {
+		let mut heap = std::collections::BinaryHeap::new();
+
+		heap.push(CurrentPrevious::new_with_previous(2, Some(0)));
+		heap.push(CurrentPrevious::new_with_previous(3, Some(9)));
+		heap.push(CurrentPrevious::new_with_previous(2, Some(1)));
+
+		assert_eq!(heap.pop(), Some(CurrentPrevious::new_with_previous(3, Some(9))));
+		assert_eq!(heap.pop(), Some(CurrentPrevious::new_with_previous(2, Some(1))));
+		assert_eq!(heap.pop(), Some(CurrentPrevious::new_with_previous(2, Some(0))));
+	}
+
+	#[test]
+	fn deref() {
+		fn takes_str(value: &str) -> usize {
+			return value.len();
+		}
+
+		let current_previous = CurrentPrevious::new_with_previous(String::from("ab"), Some(String::from("a")));
+
+		assert_eq!(takes_str(&current_previous), 2);
+		assert_eq!(*current_previous, "ab");
+	}
+
+	#[test]
+	fn as_ref_trait() {
+		fn total<V: AsRef<[i32]>>(values: V) -> i32 {
+			return values.as_ref().iter().sum();
+		}
+
+		let current_previous = CurrentPrevious::new_with_previous(vec![1, 2], Some(vec![0]));
+
+		let current: &Vec<i32> = current_previous.as_ref().into_inner();
+
+		assert_eq!(total(current), 3);
+		assert_eq!(AsRef::<Vec<i32>>::as_ref(&current_previous), &vec![1, 2]);
+	}
+
+	#[test]
+	fn const_new() {
+		const INITIAL: CurrentPrevious<i32> = CurrentPrevious::new(0);
+
+		static RESTORED: CurrentPrevious<&str> = CurrentPrevious::new_with_previous("b", Some("a"));
+
+		let mut current_previous = INITIAL;
+
+		current_previous.update(1);
+
+		assert_eq!(INITIAL.current(), &0);
+		assert_eq!(current_previous.previous(), Some(&0));
+		assert_eq!(RESTORED.previous(), Some(&"a"));
+	}
+
+	#[test]
+	fn changed() {
+		let mut current_previous = CurrentPrevious::new(0.0);
+
+		assert!(!current_previous.changed());
+
+		current_previous.update(f64::NAN);
+
+		assert!(current_previous.changed());
+
+		current_previous.clear_previous();
+
+		assert!(!current_previous.changed());
+	}
+
+	#[test]
+	fn min_max_value() {
+		let current_previous = CurrentPrevious::new_with_previous("b", Some("a"));
+
+		assert_eq!(current_previous.min_value(), &"a");
+		assert_eq!(current_previous.max_value(), &"b");
+
+		let current_previous = CurrentPrevious::new_with_previous("a", Some("b"));
+
+		assert_eq!(current_previous.min_value(), &"a");
+		assert_eq!(current_previous.max_value(), &"b");
+
+		let current_previous = CurrentPrevious::new("a");
+
+		assert_eq!(current_previous.min_value(), &"a");
+		assert_eq!(current_previous.max_value(), &"a");
+	}
+
+	#[test]
+	fn is_initial() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		assert!(current_previous.is_initial());
+
+		current_previous.update(0);
+
+		assert!(!current_previous.is_initial());
+
+		current_previous.reset(1);
+
+		assert!(current_previous.is_initial());
+	}
+
+	#[test]
+	fn previous_or_current() {
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		assert_eq!(current_previous.previous_or_current(), "a");
+
+		current_previous.update(String::from("b"));
+
+		assert_eq!(current_previous.previous_or_current(), "a");
+		assert_eq!(current_previous.current(), "b");
+	}
+
+	#[test]
+	fn previous_or() {
+		let default = String::from("default");
+
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		assert_eq!(current_previous.previous_or(&default), "default");
+
+		current_previous.update(String::from("b"));
+
+		assert_eq!(current_previous.previous_or(&default), "a");
+	}
+
+	#[test]
+	fn take() {
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		assert_eq!(current_previous.take(), "a");
+		assert_eq!(current_previous.take(), "");
+
+		assert_eq!(current_previous.current(), "");
+		assert_eq!(current_previous.previous().map(String::as_str), Some(""));
+	}
+
+	#[test]
+	fn get() {
+		let current_previous = CurrentPrevious::new_with_previous("b", Some("a"));
+
+		let values: Vec<_> = [Age::Previous, Age::Current].into_iter().map(|age| current_previous.get(age)).collect();
+
+		assert_eq!(values, vec![Some(&"a"), Some(&"b")]);
+	}
+
+	#[test]
+	fn commit() {
+		let mut current_previous = CurrentPrevious::new(0);
+
+		let mut handled = Vec::new();
+
+		for value in [1, 2, 3] {
+			current_previous.update(value);
+
+			if let Some(previous) = current_previous.commit() {
+				handled.push((previous, *current_previous.current()));
+			}
+		}
+
+		assert_eq!(handled, vec![(0, 1), (1, 2), (2, 3)]);
+		assert!(current_previous.is_initial());
+	}
+
+	#[test]
+	fn update_in_place() {
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		assert_eq!(current_previous.update_in_place(|current| current.push('b')), None);
+		assert_eq!(current_previous.update_in_place(|current| current.push('c')), Some(String::from("a")));
+
+		assert_eq!(current_previous.current(), "abc");
+		assert_eq!(current_previous.previous().map(String::as_str), Some("ab"));
+	}
 }
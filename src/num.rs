@@ -0,0 +1,346 @@
+//! Numeric operations over the values held by a `CurrentPrevious`.
+
+use std::ops::{Add, Sub};
+
+use crate::CurrentPrevious;
+
+/// A numeric type that can be converted to an `f64`, possibly losing
+/// precision.
+pub trait ToF64 {
+	/// Converts the value to an `f64`.
+	fn to_f64(&self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+	($($t:ty),*) => {
+		$(
+			impl ToF64 for $t {
+				fn to_f64(&self) -> f64 {
+					return *self as f64;
+				}
+			}
+		)*
+	};
+}
+
+impl_to_f64!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// An integer type with an absolute difference, as given by the inherent
+/// `abs_diff` methods of the primitive integer types.
+pub trait AbsDiff {
+	/// The type of the absolute difference, which is the unsigned counterpart
+	/// of signed integer types.
+	type Output;
+
+	/// Computes the absolute difference between `self` and `other`.
+	fn abs_diff(&self, other: &Self) -> Self::Output;
+}
+
+macro_rules! impl_abs_diff {
+	($($t:ty => $output:ty),*) => {
+		$(
+			impl AbsDiff for $t {
+				type Output = $output;
+
+				fn abs_diff(&self, other: &Self) -> Self::Output {
+					return <$t>::abs_diff(*self, *other);
+				}
+			}
+		)*
+	};
+}
+
+impl_abs_diff!(
+	u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+	i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+/// An integer type with checked subtraction, as given by the inherent
+/// `checked_sub` methods of the primitive integer types.
+pub trait CheckedSub: Sized {
+	/// Computes `self - other`, returning `None` if overflow occurred.
+	fn checked_sub(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_sub {
+	($($t:ty),*) => {
+		$(
+			impl CheckedSub for $t {
+				fn checked_sub(&self, other: &Self) -> Option<Self> {
+					return <$t>::checked_sub(*self, *other);
+				}
+			}
+		)*
+	};
+}
+
+impl_checked_sub!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A type that can be linearly interpolated between two values.
+pub trait Lerp {
+	/// Interpolates between `self` and `other`, such that a `t` of `0.0`
+	/// gives `self` and a `t` of `1.0` gives `other`.
+	fn lerp(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+	fn lerp(&self, other: &Self, t: f32) -> Self {
+		return self + (other - self) * t;
+	}
+}
+
+impl Lerp for f64 {
+	fn lerp(&self, other: &Self, t: f32) -> Self {
+		return self + (other - self) * f64::from(t);
+	}
+}
+
+impl <T> CurrentPrevious<T> {
+	/// Computes the difference between the `current` and `previous` values,
+	/// `current - previous`. Returns `None` if there is no `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(10);
+	///
+	/// assert_eq!(current_previous.delta(), None);
+	///
+	/// current_previous.update(15);
+	///
+	/// assert_eq!(current_previous.delta(), Some(5));
+	///
+	/// current_previous.update(12);
+	///
+	/// assert_eq!(current_previous.delta(), Some(-3));
+	/// ```
+	pub fn delta<'a>(&'a self) -> Option<<&'a T as Sub<&'a T>>::Output> where &'a T: Sub<&'a T> {
+		let (current, previous) = self.pair();
+
+		return previous.map(|previous| current - previous);
+	}
+
+	/// Sets a new `current` value of `current + delta`, as though it were
+	/// passed to [`update`](Self::update). This is the inverse of
+	/// [`delta`](Self::delta). Returns the old `previous` value, which is no
+	/// longer held.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(10);
+	///
+	/// current_previous.apply_delta(5);
+	///
+	/// assert_eq!(current_previous.current(), &15);
+	/// assert_eq!(current_previous.previous(), Some(&10));
+	/// assert_eq!(current_previous.delta(), Some(5));
+	/// ```
+	pub fn apply_delta<D>(&mut self, delta: D) -> Option<T> where for<'a> &'a T: Add<D, Output = T> {
+		return self.update_with(|current| current + delta);
+	}
+
+	/// Computes the relative change from the `previous` value to the `current`
+	/// value as a percentage. Returns `None` if there is no `previous` value,
+	/// or if the `previous` value is zero.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(80);
+	///
+	/// assert_eq!(current_previous.percent_change(), None);
+	///
+	/// current_previous.update(100);
+	///
+	/// assert_eq!(current_previous.percent_change(), Some(25.0));
+	///
+	/// current_previous.update(50);
+	///
+	/// assert_eq!(current_previous.percent_change(), Some(-50.0));
+	/// ```
+	pub fn percent_change(&self) -> Option<f64> where T: ToF64 {
+		let (current, previous) = self.pair();
+
+		let previous = previous?.to_f64();
+
+		if previous == 0.0 {
+			return None;
+		}
+
+		return Some((current.to_f64() - previous) / previous.abs() * 100.0);
+	}
+
+	/// Computes the difference between the `current` and `previous` values,
+	/// `current - previous`. Returns `None` if there is no `previous` value,
+	/// or if overflow occurred.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut counter = CurrentPrevious::new(10u32);
+	///
+	/// assert_eq!(counter.checked_delta(), None);
+	///
+	/// counter.update(15);
+	///
+	/// assert_eq!(counter.checked_delta(), Some(5));
+	///
+	/// // The counter wrapped around.
+	/// counter.update(2);
+	///
+	/// assert_eq!(counter.checked_delta(), None);
+	/// ```
+	pub fn checked_delta(&self) -> Option<T> where T: CheckedSub {
+		let (current, previous) = self.pair();
+
+		return current.checked_sub(previous?);
+	}
+
+	/// Computes the absolute difference between the `current` and `previous`
+	/// values. Returns `None` if there is no `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(10u32);
+	///
+	/// assert_eq!(current_previous.abs_diff(), None);
+	///
+	/// current_previous.update(4);
+	///
+	/// assert_eq!(current_previous.abs_diff(), Some(6));
+	/// ```
+	pub fn abs_diff(&self) -> Option<T::Output> where T: AbsDiff {
+		let (current, previous) = self.pair();
+
+		return previous.map(|previous| current.abs_diff(previous));
+	}
+
+	/// Linearly interpolates from the `previous` value to the `current` value,
+	/// such that a `t` of `0.0` gives the `previous` value and a `t` of `1.0`
+	/// gives the `current` value. If there is no `previous` value, the
+	/// `current` value is interpolated with itself.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut position = CurrentPrevious::new(0.0);
+	///
+	/// assert_eq!(position.lerp(0.5), 0.0);
+	///
+	/// position.update(10.0);
+	///
+	/// assert_eq!(position.lerp(0.0), 0.0);
+	/// assert_eq!(position.lerp(0.25), 2.5);
+	/// assert_eq!(position.lerp(1.0), 10.0);
+	/// ```
+	pub fn lerp(&self, t: f32) -> T where T: Lerp {
+		let (current, previous) = self.pair();
+
+		return previous.unwrap_or(current).lerp(current, t);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn delta() {
+		let mut current_previous = CurrentPrevious::new(1.5);
+
+		assert_eq!(current_previous.delta(), None);
+
+		current_previous.update(4.0);
+
+		assert_eq!(current_previous.delta(), Some(2.5));
+	}
+
+	#[test]
+	fn apply_delta() {
+		let mut current_previous = CurrentPrevious::new(1.5);
+
+		current_previous.apply_delta(-0.5);
+		current_previous.apply_delta(0.25);
+
+		assert_eq!(current_previous.current(), &1.25);
+		assert_eq!(current_previous.delta(), Some(0.25));
+	}
+
+	#[test]
+	fn percent_change() {
+		let current_previous = CurrentPrevious::new_with_previous(-50.0, Some(-100.0));
+
+		assert_eq!(current_previous.percent_change(), Some(50.0));
+
+		let current_previous = CurrentPrevious::new_with_previous(5u8, Some(0u8));
+
+		assert_eq!(current_previous.percent_change(), None);
+
+		let current_previous = CurrentPrevious::new_with_previous(0u8, Some(5u8));
+
+		assert_eq!(current_previous.percent_change(), Some(-100.0));
+	}
+
+	#[test]
+	fn checked_delta() {
+		let current_previous = CurrentPrevious::new_with_previous(i32::MIN, Some(1));
+
+		assert_eq!(current_previous.checked_delta(), None);
+
+		let current_previous = CurrentPrevious::new_with_previous(-1i32, Some(1));
+
+		assert_eq!(current_previous.checked_delta(), Some(-2));
+	}
+
+	#[test]
+	fn abs_diff() {
+		let current_previous = CurrentPrevious::new_with_previous(i8::MIN, Some(i8::MAX));
+
+		assert_eq!(current_previous.abs_diff(), Some(255u8));
+
+		let current_previous = CurrentPrevious::new_with_previous(3u64, Some(7u64));
+
+		assert_eq!(current_previous.abs_diff(), Some(4));
+	}
+
+	#[test]
+	fn lerp() {
+		#[derive(Debug, PartialEq)]
+		struct Point {
+			x: f32,
+			y: f32
+		}
+
+		impl Lerp for Point {
+			fn lerp(&self, other: &Self, t: f32) -> Self {
+				return Point {
+					x: self.x.lerp(&other.x, t),
+					y: self.y.lerp(&other.y, t)
+				};
+			}
+		}
+
+		let mut current_previous = CurrentPrevious::new(Point { x: 0.0, y: 0.0 });
+
+		current_previous.update(Point { x: 2.0, y: -4.0 });
+
+		assert_eq!(current_previous.lerp(0.5), Point { x: 1.0, y: -2.0 });
+	}
+
+	#[test]
+	fn delta_wrapping() {
+		use std::num::Wrapping;
+
+		let current_previous = CurrentPrevious::new_with_previous(Wrapping(1u8), Some(Wrapping(3u8)));
+
+		assert_eq!(current_previous.delta(), Some(Wrapping(254u8)));
+	}
+}
@@ -0,0 +1,154 @@
+//! A fixed-capacity ring buffer used as allocation-free backing storage.
+
+use std::iter::FusedIterator;
+
+/// A ring buffer holding up to `N` values, indexed from newest to oldest.
+#[derive(Clone, Debug)]
+pub(crate) struct Ring<T, const N: usize> {
+	slots: [Option<T>; N],
+	head: usize,
+	len: usize
+}
+
+impl <T, const N: usize> Ring<T, N> {
+	const EMPTY: Option<T> = None;
+
+	pub(crate) const fn new() -> Self {
+		return Self {
+			slots: [Self::EMPTY; N],
+			head: 0,
+			len: 0
+		};
+	}
+
+	pub(crate) fn len(&self) -> usize {
+		return self.len;
+	}
+
+	pub(crate) fn is_full(&self) -> bool {
+		return self.len == N;
+	}
+
+	/// Pushes a new value, returning the value evicted to make room for it.
+	pub(crate) fn push(&mut self, value: T) -> Option<T> {
+		if N == 0 {
+			return Some(value);
+		}
+
+		let evicted = self.slots[self.head].replace(value);
+
+		self.head = (self.head + 1) % N;
+		self.len = (self.len + 1).min(N);
+
+		return evicted;
+	}
+
+	/// Gets the value at the given index, where `0` is the newest value.
+	pub(crate) fn get(&self, index: usize) -> Option<&T> {
+		return self.slot(index).and_then(|slot| self.slots[slot].as_ref());
+	}
+
+	/// Gets the value at the given index, where `0` is the newest value.
+	pub(crate) fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		return self.slot(index).and_then(|slot| self.slots[slot].as_mut());
+	}
+
+	/// Removes all values.
+	pub(crate) fn clear(&mut self) {
+		self.slots = [Self::EMPTY; N];
+		self.head = 0;
+		self.len = 0;
+	}
+
+	/// Returns an iterator from the newest value to the oldest value.
+	pub(crate) fn iter(&self) -> Iter<'_, T, N> {
+		return Iter {
+			ring: self,
+			front: 0,
+			back: self.len
+		};
+	}
+
+	fn slot(&self, index: usize) -> Option<usize> {
+		if index >= self.len {
+			return None;
+		}
+
+		return Some((self.head + N - 1 - index) % N);
+	}
+}
+
+/// An iterator over the values of a [`Ring`], from newest to oldest.
+#[derive(Clone, Debug)]
+pub(crate) struct Iter<'a, T, const N: usize> {
+	ring: &'a Ring<T, N>,
+	front: usize,
+	back: usize
+}
+
+impl <'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+
+		self.front += 1;
+
+		return self.ring.get(self.front - 1);
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+
+		return (len, Some(len));
+	}
+}
+
+impl <T, const N: usize> DoubleEndedIterator for Iter<'_, T, N> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front == self.back {
+			return None;
+		}
+
+		self.back -= 1;
+
+		return self.ring.get(self.back);
+	}
+}
+
+impl <T, const N: usize> ExactSizeIterator for Iter<'_, T, N> {}
+
+impl <T, const N: usize> FusedIterator for Iter<'_, T, N> {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn push_and_get() {
+		let mut ring: Ring<i32, 3> = Ring::new();
+
+		assert_eq!(ring.push(0), None);
+		assert_eq!(ring.push(1), None);
+		assert_eq!(ring.push(2), None);
+		assert_eq!(ring.push(3), Some(0));
+		assert!(ring.is_full());
+
+		assert_eq!(ring.get(0), Some(&3));
+		assert_eq!(ring.get(2), Some(&1));
+		assert_eq!(ring.get(3), None);
+		assert_eq!(ring.iter().collect::<Vec<_>>(), vec![&3, &2, &1]);
+		assert_eq!(ring.iter().rev().collect::<Vec<_>>(), vec![&1, &2, &3]);
+	}
+
+	#[test]
+	fn zero_capacity() {
+		let mut ring: Ring<i32, 0> = Ring::new();
+
+		assert_eq!(ring.push(0), Some(0));
+		assert_eq!(ring.len(), 0);
+		assert_eq!(ring.get(0), None);
+	}
+}
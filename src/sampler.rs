@@ -0,0 +1,178 @@
+//! Periodic sampling of a value into a `CurrentPrevious` on a background
+//! thread.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::CurrentPrevious;
+use crate::sync::lock;
+
+/// Polls a closure on an interval from a background thread, feeding each
+/// result into a shared `CurrentPrevious` as its new `current` value.
+///
+/// The thread is stopped when the `Sampler` is stopped or dropped.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::sampler::Sampler;
+/// # use std::time::Duration;
+/// let mut count = 0;
+///
+/// let sampler = Sampler::spawn(Duration::from_millis(1), move || {
+///     count += 1;
+///
+///     return count;
+/// });
+///
+/// while sampler.lock().current() < &3 {
+///     std::thread::yield_now();
+/// }
+///
+/// let current_previous = sampler.stop();
+///
+/// assert!(current_previous.current() >= &3);
+/// ```
+#[derive(Debug)]
+pub struct Sampler<T> {
+	shared: Arc<Mutex<CurrentPrevious<T>>>,
+	stop: Arc<AtomicBool>,
+	thread: Option<JoinHandle<()>>
+}
+
+impl <T: Send + 'static> Sampler<T> {
+	/// Takes an initial sample on the calling thread, then spawns a thread
+	/// that takes a new sample every `interval`.
+	pub fn spawn<F>(interval: Duration, mut sample: F) -> Self where F: FnMut() -> T + Send + 'static {
+		let shared = Arc::new(Mutex::new(CurrentPrevious::new(sample())));
+		let stop = Arc::new(AtomicBool::new(false));
+
+		let thread = {
+			let shared = Arc::clone(&shared);
+			let stop = Arc::clone(&stop);
+
+			thread::spawn(move || {
+				loop {
+					let deadline = Instant::now() + interval;
+
+					while !stop.load(Ordering::Acquire) && Instant::now() < deadline {
+						thread::park_timeout(deadline.saturating_duration_since(Instant::now()));
+					}
+
+					if stop.load(Ordering::Acquire) {
+						return;
+					}
+
+					let new = sample();
+
+					lock(&shared).update(new);
+				}
+			})
+		};
+
+		return Self {
+			shared,
+			stop,
+			thread: Some(thread)
+		};
+	}
+}
+
+impl <T> Sampler<T> {
+	/// Locks the sampled values for reading, blocking the sampling thread
+	/// until the guard is dropped.
+	pub fn lock(&self) -> MutexGuard<'_, CurrentPrevious<T>> {
+		return lock(&self.shared);
+	}
+
+	/// Gets a copy of the sampled values.
+	pub fn get(&self) -> CurrentPrevious<T> where T: Clone {
+		return self.lock().clone();
+	}
+
+	/// Stops the sampling thread, waiting for any sample in progress, and
+	/// returns the sampled values.
+	pub fn stop(self) -> CurrentPrevious<T> {
+		let shared = Arc::clone(&self.shared);
+
+		// Dropping the `Sampler` joins the sampling thread, so `shared` is
+		// left as the only reference to the values.
+		drop(self);
+
+		let Ok(mutex) = Arc::try_unwrap(shared) else {
+			unreachable!("the sampling thread has stopped");
+		};
+
+		return mutex.into_inner().unwrap_or_else(|error| error.into_inner());
+	}
+
+	fn join(&mut self) {
+		let Some(thread) = self.thread.take() else {
+			return;
+		};
+
+		self.stop.store(true, Ordering::Release);
+		thread.thread().unpark();
+
+		// A panic in the sampling closure has already been reported by the
+		// sampling thread, and has left the values as they were.
+		let _ = thread.join();
+	}
+}
+
+impl <T> Drop for Sampler<T> {
+	fn drop(&mut self) {
+		self.join();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sample_until_stopped() {
+		let sampler = Sampler::spawn(Duration::from_millis(1), {
+			let mut value = 0_u32;
+
+			move || {
+				value += 1;
+
+				return value;
+			}
+		});
+
+		while sampler.lock().current() < &4 {
+			thread::yield_now();
+		}
+
+		let sampled = sampler.get();
+
+		assert_eq!(sampled.previous().map(|previous| previous + 1), Some(*sampled.current()));
+
+		let stopped = sampler.stop();
+
+		assert!(stopped.current() >= sampled.current());
+	}
+
+	#[test]
+	fn stop_without_clone() {
+		#[derive(Debug, PartialEq)]
+		struct Sample(u32);
+
+		let sampler = Sampler::spawn(Duration::from_secs(3600), || Sample(1));
+
+		assert_eq!(sampler.stop().into_parts(), (Sample(1), None));
+	}
+
+	#[test]
+	fn drop_stops_thread() {
+		let sampler = Sampler::spawn(Duration::from_secs(3600), || "sample");
+
+		assert_eq!(sampler.get().current(), &"sample");
+
+		drop(sampler);
+	}
+}
@@ -0,0 +1,223 @@
+//! A sequence lock around a `current` and `previous` value, giving readers
+//! consistent snapshots without locking.
+
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::hint;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::atomic::{self, AtomicUsize, Ordering};
+
+use crate::CurrentPrevious;
+
+/// Tracks a `current` value along with the `previous` value that it replaced,
+/// letting any number of threads read both together while another updates
+/// them, without locking.
+///
+/// Readers never block, but retry if an update happened while they were
+/// reading, so this suits values that are read far more often than they are
+/// updated, such as per-frame state and metrics. Updates from more than one
+/// thread at once are made one at a time.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::seqlock::SeqLockCurrentPrevious;
+/// # use std::thread;
+/// let position = SeqLockCurrentPrevious::new((0, 0));
+///
+/// thread::scope(|scope| {
+///     scope.spawn(|| {
+///         for x in 1..=100 {
+///             position.update((x, -x));
+///         }
+///     });
+///
+///     scope.spawn(|| {
+///         let (current, previous) = position.pair();
+///
+///         assert_eq!(current.0, -current.1);
+///         if let Some(previous) = previous {
+///             assert_eq!(previous.0, current.0 - 1);
+///         }
+///     });
+/// });
+///
+/// assert_eq!(position.current(), (100, -100));
+/// ```
+pub struct SeqLockCurrentPrevious<T: Copy> {
+	sequence: AtomicUsize,
+	values: UnsafeCell<(T, Option<T>)>
+}
+
+// SAFETY: The values are only written while the sequence is odd, which only
+// one thread can make it at a time, and are only returned to readers if the
+// sequence shows that no write overlapped the read.
+unsafe impl <T: Copy + Send> Sync for SeqLockCurrentPrevious<T> {}
+
+impl <T: Copy> SeqLockCurrentPrevious<T> {
+	/// Creates a new `SeqLockCurrentPrevious` holding the `initial` value as
+	/// its `current` value, with no `previous` value.
+	pub const fn new(initial: T) -> Self {
+		return Self {
+			sequence: AtomicUsize::new(0),
+			values: UnsafeCell::new((initial, None))
+		};
+	}
+
+	/// Reads the `current` and `previous` values together.
+	pub fn pair(&self) -> (T, Option<T>) {
+		loop {
+			let before = self.sequence.load(Ordering::Acquire);
+
+			if before % 2 == 1 {
+				hint::spin_loop();
+
+				continue;
+			}
+
+			// SAFETY: The read may race with a write, so it is made into a
+			// `MaybeUninit`, and is only assumed to be initialized once the
+			// sequence shows that no write overlapped it.
+			let values = unsafe { ptr::read_volatile(self.values.get().cast::<MaybeUninit<(T, Option<T>)>>()) };
+
+			atomic::fence(Ordering::Acquire);
+
+			if self.sequence.load(Ordering::Relaxed) == before {
+				// SAFETY: No write overlapped the read, so it is a copy of
+				// values that were fully written.
+				return unsafe { values.assume_init() };
+			}
+		}
+	}
+
+	/// Gets the `current` value.
+	pub fn current(&self) -> T {
+		return self.pair().0;
+	}
+
+	/// Gets the `previous` value, if there is one.
+	pub fn previous(&self) -> Option<T> {
+		return self.pair().1;
+	}
+
+	/// Reads the `current` and `previous` values together into a
+	/// `CurrentPrevious`.
+	pub fn load(&self) -> CurrentPrevious<T> {
+		let (current, previous) = self.pair();
+
+		return CurrentPrevious::new_with_previous(current, previous);
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value. Returns the old `previous` value, if there was one.
+	pub fn update(&self, new: T) -> Option<T> {
+		let mut sequence = self.sequence.load(Ordering::Relaxed);
+
+		loop {
+			if sequence % 2 == 1 {
+				hint::spin_loop();
+
+				sequence = self.sequence.load(Ordering::Relaxed);
+
+				continue;
+			}
+
+			match self.sequence.compare_exchange_weak(sequence, sequence + 1, Ordering::Acquire, Ordering::Relaxed) {
+				Ok(_) => break,
+				Err(actual) => sequence = actual
+			}
+		}
+
+		atomic::fence(Ordering::Release);
+
+		// SAFETY: The sequence is odd, so no other thread is writing, and
+		// readers discard anything that they read while it is.
+		let evicted = unsafe {
+			let (current, previous) = ptr::read_volatile(self.values.get());
+
+			ptr::write_volatile(self.values.get(), (new, Some(current)));
+
+			previous
+		};
+
+		self.sequence.store(sequence + 2, Ordering::Release);
+
+		return evicted;
+	}
+
+	/// Consumes the `SeqLockCurrentPrevious`, returning the held values as a
+	/// `CurrentPrevious`.
+	pub fn into_inner(self) -> CurrentPrevious<T> {
+		let (current, previous) = self.values.into_inner();
+
+		return CurrentPrevious::new_with_previous(current, previous);
+	}
+}
+
+impl <T: Copy + fmt::Debug> fmt::Debug for SeqLockCurrentPrevious<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (current, previous) = self.pair();
+
+		return f.debug_struct("SeqLockCurrentPrevious")
+			.field("current", &current)
+			.field("previous", &previous)
+			.finish();
+	}
+}
+
+impl <T: Copy + Default> Default for SeqLockCurrentPrevious<T> {
+	fn default() -> Self {
+		return Self::new(T::default());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use std::thread;
+
+	#[test]
+	fn consistent_pairs() {
+		let tracker = SeqLockCurrentPrevious::new([0_u64; 8]);
+
+		thread::scope(|scope| {
+			for writer in 0..2 {
+				let tracker = &tracker;
+
+				scope.spawn(move || {
+					for value in 0..10_000 {
+						tracker.update([writer * 10_000 + value; 8]);
+					}
+				});
+			}
+
+			for _ in 0..2 {
+				scope.spawn(|| {
+					for _ in 0..10_000 {
+						let (current, previous) = tracker.pair();
+
+						assert!(current.iter().all(|value| *value == current[0]));
+						if let Some(previous) = previous {
+							assert!(previous.iter().all(|value| *value == previous[0]));
+						}
+					}
+				});
+			}
+		});
+
+		assert!(tracker.previous().is_some());
+	}
+
+	#[test]
+	fn update() {
+		let tracker = SeqLockCurrentPrevious::default();
+
+		assert_eq!(tracker.update(1), None);
+		assert_eq!(tracker.update(2), Some(0));
+		assert_eq!(tracker.load().into_parts(), (2, Some(1)));
+		assert_eq!(format!("{:?}", tracker), "SeqLockCurrentPrevious { current: 2, previous: Some(1) }");
+		assert_eq!(tracker.into_inner().into_parts(), (2, Some(1)));
+	}
+}
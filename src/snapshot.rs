@@ -0,0 +1,65 @@
+//! Checkpointing of the full state of a `CurrentPrevious`.
+
+use crate::CurrentPrevious;
+
+/// A copy of the full state of a `CurrentPrevious`, including both values.
+///
+/// This `struct` is created by [`CurrentPrevious::snapshot`], and can be
+/// restored with [`CurrentPrevious::restore`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Snapshot<T> {
+	state: CurrentPrevious<T>
+}
+
+impl <T> CurrentPrevious<T> {
+	/// Takes a [`Snapshot`] of the full state of the `CurrentPrevious`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::CurrentPrevious;
+	/// let mut current_previous = CurrentPrevious::new(0);
+	///
+	/// current_previous.update(1);
+	///
+	/// let snapshot = current_previous.snapshot();
+	///
+	/// current_previous.update(2);
+	/// current_previous.update(3);
+	///
+	/// current_previous.restore(snapshot);
+	///
+	/// assert_eq!(current_previous.current(), &1);
+	/// assert_eq!(current_previous.previous(), Some(&0));
+	/// ```
+	pub fn snapshot(&self) -> Snapshot<T> where T: Clone {
+		return Snapshot {
+			state: self.clone()
+		};
+	}
+
+	/// Restores the full state of the `CurrentPrevious` from a [`Snapshot`].
+	pub fn restore(&mut self, snapshot: Snapshot<T>) {
+		*self = snapshot.state;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::CurrentPrevious;
+
+	#[test]
+	fn restore() {
+		let mut current_previous = CurrentPrevious::new(String::from("a"));
+
+		let snapshot = current_previous.snapshot();
+
+		current_previous.update(String::from("b"));
+		current_previous.restore(snapshot.clone());
+
+		assert_eq!(current_previous.current(), "a");
+		assert_eq!(current_previous.previous(), None);
+
+		assert_eq!(snapshot, current_previous.snapshot());
+	}
+}
@@ -0,0 +1,110 @@
+use crate::CurrentPrevious;
+use crate::num::ToF64;
+
+/// Tracks a `current` value along with the `previous` value that it replaced,
+/// and an exponential moving average of every value that it has held.
+///
+/// Each update moves the average towards the new value by a fraction
+/// `alpha`, so a larger `alpha` follows the values more closely, and a
+/// smaller `alpha` smooths them more.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::stats::Ema;
+/// let mut temperature = Ema::new(20.0, 0.5);
+///
+/// temperature.update(30.0);
+///
+/// assert_eq!(temperature.current(), &30.0);
+/// assert_eq!(temperature.smoothed(), 25.0);
+///
+/// temperature.update(30.0);
+///
+/// assert_eq!(temperature.smoothed(), 27.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ema<T> {
+	values: CurrentPrevious<T>,
+	smoothed: f64,
+	alpha: f64
+}
+
+impl <T: ToF64> Ema<T> {
+	/// Creates a new `Ema` holding the `initial` value as its `current` value
+	/// and as its average, which moves towards each new value by `alpha`.
+	///
+	/// # Panics
+	///
+	/// Panics if `alpha` is not greater than `0.0` and at most `1.0`.
+	pub fn new(initial: T, alpha: f64) -> Self {
+		assert!(alpha > 0.0 && alpha <= 1.0, "an Ema's alpha must be in the range (0, 1]");
+
+		return Self {
+			smoothed: initial.to_f64(),
+			values: CurrentPrevious::new(initial),
+			alpha
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return self.values.current();
+	}
+
+	/// Gets an optional reference to the `previous` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.values.previous();
+	}
+
+	/// Gets a reference to the held values.
+	pub fn values(&self) -> &CurrentPrevious<T> {
+		return &self.values;
+	}
+
+	/// Gets the exponential moving average.
+	pub fn smoothed(&self) -> f64 {
+		return self.smoothed;
+	}
+
+	/// Gets the fraction by which the average moves towards each new value.
+	pub fn alpha(&self) -> f64 {
+		return self.alpha;
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value, and moves the average towards the new value.
+	pub fn update(&mut self, new: T) {
+		self.smoothed += self.alpha * (new.to_f64() - self.smoothed);
+		self.values.update(new);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn smoothing() {
+		let mut ema = Ema::new(0_u8, 0.25);
+
+		ema.update(8);
+		ema.update(8);
+
+		assert_eq!(ema.smoothed(), 3.5);
+		assert_eq!(ema.previous(), Some(&8));
+		assert_eq!(ema.alpha(), 0.25);
+
+		let mut raw = Ema::new(1.0, 1.0);
+
+		raw.update(5.0);
+
+		assert_eq!(raw.smoothed(), *raw.current());
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_alpha() {
+		Ema::new(0.0, 0.0);
+	}
+}
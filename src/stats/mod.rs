@@ -0,0 +1,12 @@
+//! Trackers that derive statistics from the values they have held, alongside
+//! the `current` and `previous` values.
+
+mod ema;
+mod rolling;
+mod second_order;
+mod watermarks;
+
+pub use ema::Ema;
+pub use rolling::Rolling;
+pub use second_order::SecondOrder;
+pub use watermarks::Watermarks;
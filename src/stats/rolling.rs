@@ -0,0 +1,169 @@
+use crate::CurrentPrevious;
+use crate::num::ToF64;
+use crate::ring::Ring;
+
+/// Tracks a `current` value along with the `previous` value that it replaced,
+/// and statistics over a window of up to the last `N` values, including the
+/// `current` value.
+///
+/// The window is held in a fixed-size array, so a `Rolling` never allocates.
+/// The `previous` value is held apart from the window, so it is kept even if
+/// `N` is one.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::stats::Rolling;
+/// let mut latency: Rolling<u32, 3> = Rolling::new(10);
+///
+/// latency.update(20);
+/// latency.update(30);
+/// latency.update(40);
+///
+/// assert_eq!(latency.previous(), Some(&30));
+/// assert_eq!(latency.mean(), 30.0);
+/// assert_eq!(latency.min(), &20);
+/// assert_eq!(latency.max(), &40);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Rolling<T, const N: usize> {
+	values: CurrentPrevious<T>,
+	window: Ring<T, N>
+}
+
+// A `Rolling` always holds at least its `current` value, so it is never empty.
+#[allow(clippy::len_without_is_empty)]
+impl <T: Clone, const N: usize> Rolling<T, N> {
+	/// Creates a new `Rolling` holding the `initial` value as its `current`
+	/// value.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is zero.
+	pub fn new(initial: T) -> Self {
+		assert!(N > 0, "a Rolling must have room for its current value");
+
+		let mut window = Ring::new();
+
+		window.push(initial.clone());
+
+		return Self {
+			values: CurrentPrevious::new(initial),
+			window
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return self.values.current();
+	}
+
+	/// Gets an optional reference to the `previous` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.values.previous();
+	}
+
+	/// Gets a reference to the held values.
+	pub fn values(&self) -> &CurrentPrevious<T> {
+		return &self.values;
+	}
+
+	/// Gets the number of values in the window, which is at most `N`.
+	pub fn len(&self) -> usize {
+		return self.window.len();
+	}
+
+	/// Returns an iterator over the values in the window, from the `current`
+	/// value to the oldest.
+	pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+		return self.window.iter();
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value. If the window was full, the oldest value is returned,
+	/// as it has left the window.
+	pub fn update(&mut self, new: T) -> Option<T> {
+		let evicted = self.window.push(new.clone());
+
+		self.values.update(new);
+
+		return evicted;
+	}
+
+	/// Computes the mean of the values in the window.
+	pub fn mean(&self) -> f64 where T: ToF64 {
+		return self.iter().map(ToF64::to_f64).sum::<f64>() / self.len() as f64;
+	}
+
+	/// Computes the population variance of the values in the window.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::stats::Rolling;
+	/// let mut rolling: Rolling<f64, 4> = Rolling::new(2.0);
+	///
+	/// rolling.update(4.0);
+	/// rolling.update(4.0);
+	/// rolling.update(6.0);
+	///
+	/// assert_eq!(rolling.variance(), 2.0);
+	/// ```
+	pub fn variance(&self) -> f64 where T: ToF64 {
+		let mean = self.mean();
+
+		return self.iter().map(|value| (value.to_f64() - mean).powi(2)).sum::<f64>() / self.len() as f64;
+	}
+
+	/// Gets a reference to the smallest value in the window. Of values that are
+	/// incomparable or equal, the most recent is returned.
+	pub fn min(&self) -> &T where T: PartialOrd {
+		return self.iter().reduce(|min, value| if value < min { value } else { min }).expect("a Rolling is never empty");
+	}
+
+	/// Gets a reference to the largest value in the window. Of values that are
+	/// incomparable or equal, the most recent is returned.
+	pub fn max(&self) -> &T where T: PartialOrd {
+		return self.iter().reduce(|max, value| if value > max { value } else { max }).expect("a Rolling is never empty");
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn window() {
+		let mut rolling: Rolling<i32, 2> = Rolling::new(5);
+
+		assert_eq!(rolling.mean(), 5.0);
+		assert_eq!(rolling.variance(), 0.0);
+
+		assert_eq!(rolling.update(1), None);
+		assert_eq!(rolling.update(3), Some(5));
+
+		assert_eq!(rolling.len(), 2);
+		assert_eq!(rolling.current(), &3);
+		assert_eq!(rolling.mean(), 2.0);
+		assert_eq!(rolling.variance(), 1.0);
+		assert_eq!(rolling.min(), &1);
+		assert_eq!(rolling.max(), &3);
+	}
+
+	#[test]
+	fn window_of_one() {
+		let mut rolling: Rolling<i32, 1> = Rolling::new(1);
+
+		assert_eq!(rolling.update(2), Some(1));
+
+		assert_eq!(rolling.previous(), Some(&1));
+		assert_eq!(rolling.values().pair(), (&2, Some(&1)));
+		assert_eq!(rolling.mean(), 2.0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_window() {
+		let _: Rolling<i32, 0> = Rolling::new(0);
+	}
+}
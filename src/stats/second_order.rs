@@ -0,0 +1,103 @@
+use std::ops::Sub;
+
+use crate::CurrentPrevious;
+
+/// Tracks a `current` value along with the `previous` value that it replaced,
+/// and the first and second differences across updates, which estimate the
+/// velocity and acceleration of the value per update.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::stats::SecondOrder;
+/// let mut position = SecondOrder::new(0);
+///
+/// position.update(1);
+///
+/// assert_eq!(position.velocity(), Some(&1));
+/// assert_eq!(position.acceleration(), None);
+///
+/// position.update(4);
+///
+/// assert_eq!(position.velocity(), Some(&3));
+/// assert_eq!(position.acceleration(), Some(&2));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SecondOrder<T> {
+	values: CurrentPrevious<T>,
+	velocity: Option<T>,
+	acceleration: Option<T>
+}
+
+impl <T> SecondOrder<T> {
+	/// Creates a new `SecondOrder` holding the `initial` value as its
+	/// `current` value, with no differences yet.
+	pub const fn new(initial: T) -> Self {
+		return Self {
+			values: CurrentPrevious::new(initial),
+			velocity: None,
+			acceleration: None
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return self.values.current();
+	}
+
+	/// Gets an optional reference to the `previous` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.values.previous();
+	}
+
+	/// Gets a reference to the held values.
+	pub fn values(&self) -> &CurrentPrevious<T> {
+		return &self.values;
+	}
+
+	/// Gets an optional reference to the first difference, `current -
+	/// previous`, which is `None` until the first update.
+	pub fn velocity(&self) -> Option<&T> {
+		return self.velocity.as_ref();
+	}
+
+	/// Gets an optional reference to the second difference, which is the
+	/// change in the first difference over the last update, and is `None`
+	/// until the second update.
+	pub fn acceleration(&self) -> Option<&T> {
+		return self.acceleration.as_ref();
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value, and updates the differences.
+	pub fn update(&mut self, new: T) where for<'a> &'a T: Sub<&'a T, Output = T> {
+		let velocity = &new - self.values.current();
+
+		if let Some(previous_velocity) = &self.velocity {
+			self.acceleration = Some(&velocity - previous_velocity);
+		}
+
+		self.velocity = Some(velocity);
+		self.values.update(new);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn constant_acceleration() {
+		let mut height = SecondOrder::new(100.0);
+
+		for (time, expected) in [(1.0, 95.0), (2.0, 80.0), (3.0, 55.0)] {
+			height.update(100.0 - 5.0 * time * time);
+
+			assert_eq!(height.current(), &expected);
+		}
+
+		assert_eq!(height.previous(), Some(&80.0));
+		assert_eq!(height.velocity(), Some(&-25.0));
+		assert_eq!(height.acceleration(), Some(&-10.0));
+	}
+}
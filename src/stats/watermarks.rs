@@ -0,0 +1,113 @@
+use crate::CurrentPrevious;
+
+/// Tracks a `current` value along with the `previous` value that it replaced,
+/// and the smallest and largest values that it has held since it was created,
+/// or since its watermarks were last reset.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::stats::Watermarks;
+/// let mut temperature = Watermarks::new(20);
+///
+/// temperature.update(25);
+/// temperature.update(15);
+/// temperature.update(18);
+///
+/// assert_eq!(temperature.current(), &18);
+/// assert_eq!(temperature.min(), &15);
+/// assert_eq!(temperature.max(), &25);
+///
+/// temperature.reset_watermarks();
+///
+/// assert_eq!(temperature.min(), &18);
+/// assert_eq!(temperature.max(), &18);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Watermarks<T> {
+	values: CurrentPrevious<T>,
+	min: T,
+	max: T
+}
+
+impl <T: Clone> Watermarks<T> {
+	/// Creates a new `Watermarks` holding the `initial` value as its `current`
+	/// value and as both of its watermarks.
+	pub fn new(initial: T) -> Self {
+		return Self {
+			min: initial.clone(),
+			max: initial.clone(),
+			values: CurrentPrevious::new(initial)
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return self.values.current();
+	}
+
+	/// Gets an optional reference to the `previous` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.values.previous();
+	}
+
+	/// Gets a reference to the held values.
+	pub fn values(&self) -> &CurrentPrevious<T> {
+		return &self.values;
+	}
+
+	/// Gets a reference to the smallest value held since the watermarks were
+	/// last reset.
+	pub fn min(&self) -> &T {
+		return &self.min;
+	}
+
+	/// Gets a reference to the largest value held since the watermarks were
+	/// last reset.
+	pub fn max(&self) -> &T {
+		return &self.max;
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value, and moves the watermarks if it is beyond them.
+	pub fn update(&mut self, new: T) where T: PartialOrd {
+		if new < self.min {
+			self.min = new.clone();
+		}
+
+		if new > self.max {
+			self.max = new.clone();
+		}
+
+		self.values.update(new);
+	}
+
+	/// Resets both watermarks to the `current` value.
+	pub fn reset_watermarks(&mut self) {
+		self.min = self.values.current().clone();
+		self.max = self.values.current().clone();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn watermarks() {
+		let mut latency = Watermarks::new(String::from("m"));
+
+		latency.update(String::from("z"));
+		latency.update(String::from("a"));
+
+		assert_eq!(latency.min(), "a");
+		assert_eq!(latency.max(), "z");
+		assert_eq!(latency.previous().map(String::as_str), Some("z"));
+
+		latency.reset_watermarks();
+		latency.update(String::from("b"));
+
+		assert_eq!(latency.min(), "a");
+		assert_eq!(latency.max(), "b");
+	}
+}
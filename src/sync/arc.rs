@@ -0,0 +1,281 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::CurrentPrevious;
+
+use super::lock;
+
+/// Tracks a `current` value along with the `previous` value that it replaced,
+/// each held in an [`Arc`], so that large values can be read from many threads
+/// without being cloned.
+///
+/// Both `Arc`s are held together in a snapshot that is swapped atomically on
+/// update, so reads are wait-free and always see values that were held
+/// together. Updates are made one at a time, and only wait for the reads that
+/// were already in progress when they swapped the snapshot, so reads that
+/// start later cannot hold an update up however often they are made. A reader
+/// keeps the values that it read for as long as it holds their `Arc`s,
+/// regardless of later updates.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::sync::ArcCurrentPrevious;
+/// let config = ArcCurrentPrevious::new(vec!["a.example"]);
+///
+/// let before = config.current();
+///
+/// config.update(vec!["a.example", "b.example"]);
+///
+/// let (current, previous) = config.pair();
+///
+/// assert_eq!(current.len(), 2);
+/// assert!(previous.is_some_and(|previous| std::sync::Arc::ptr_eq(&previous, &before)));
+/// ```
+pub struct ArcCurrentPrevious<T> {
+	// Made by `Arc::into_raw`, and only freed by an update once no reader can
+	// still be about to take a strong reference to it.
+	snapshot: AtomicPtr<CurrentPrevious<Arc<T>>>,
+	// Readers are counted under the parity of the generation that they saw,
+	// so that an update can wait for earlier readers without waiting for the
+	// readers that start after it.
+	generation: AtomicUsize,
+	readers: [AtomicUsize; 2],
+	writer: Mutex<()>,
+	_values: PhantomData<Arc<CurrentPrevious<Arc<T>>>>
+}
+
+impl <T> ArcCurrentPrevious<T> {
+	/// Creates a new `ArcCurrentPrevious` holding the `initial` value as its
+	/// `current` value, with no `previous` value.
+	pub fn new(initial: T) -> Self {
+		return Self::from(Arc::new(initial));
+	}
+
+	fn snapshot(&self) -> Arc<CurrentPrevious<Arc<T>>> {
+		let readers = &self.readers[self.generation.load(Ordering::SeqCst) % 2];
+
+		readers.fetch_add(1, Ordering::SeqCst);
+
+		let snapshot = self.snapshot.load(Ordering::SeqCst);
+
+		// SAFETY: The snapshot was made by `Arc::into_raw`, and an update that
+		// swaps it out waits for both reader counts to drain of the reads
+		// counted before the swap before freeing it, so it is still alive
+		// while this read is counted.
+		let snapshot = unsafe {
+			Arc::increment_strong_count(snapshot);
+			Arc::from_raw(snapshot)
+		};
+
+		readers.fetch_sub(1, Ordering::SeqCst);
+
+		return snapshot;
+	}
+
+	/// Gets the `current` and `previous` values, read together.
+	pub fn pair(&self) -> (Arc<T>, Option<Arc<T>>) {
+		let snapshot = self.snapshot();
+
+		return (Arc::clone(snapshot.current()), snapshot.previous().cloned());
+	}
+
+	/// Gets the `current` value.
+	pub fn current(&self) -> Arc<T> {
+		return Arc::clone(self.snapshot().current());
+	}
+
+	/// Gets the `previous` value, if there is one.
+	pub fn previous(&self) -> Option<Arc<T>> {
+		return self.snapshot().previous().cloned();
+	}
+
+	/// Gets the `current` and `previous` values, read together, as a
+	/// `CurrentPrevious`.
+	pub fn load(&self) -> CurrentPrevious<Arc<T>> {
+		return CurrentPrevious::clone(&self.snapshot());
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value. Returns the old `previous` value, if there was one.
+	///
+	/// The new value is moved into its `Arc` before any other update is
+	/// waited for.
+	pub fn update(&self, new: T) -> Option<Arc<T>> {
+		return self.update_arc(Arc::new(new));
+	}
+
+	/// Sets a new `current` value that is already in an `Arc`, making the old
+	/// `current` value the `previous` value. Returns the old `previous` value,
+	/// if there was one.
+	pub fn update_arc(&self, new: Arc<T>) -> Option<Arc<T>> {
+		let _writer = lock(&self.writer);
+
+		let old = self.snapshot.load(Ordering::SeqCst);
+
+		// SAFETY: Only an update frees a snapshot, and this one holds the
+		// writer lock, so the snapshot is still alive.
+		let mut values = CurrentPrevious::clone(unsafe { &*old });
+		let previous = values.update(new);
+
+		self.snapshot.store(Arc::into_raw(Arc::new(values)).cast_mut(), Ordering::SeqCst);
+
+		// Any reader that may still be about to take a strong reference to the
+		// old snapshot was counted before the swap, under whichever parity it
+		// saw, which may be stale. Moving the generation on twice, and each
+		// time waiting for the count of the parity that it moved from to
+		// drain, waits for both counts while new readers are counted under the
+		// other parity, so each wait is only for reads already in progress.
+		for _ in 0..2 {
+			let parity = self.generation.fetch_add(1, Ordering::SeqCst) % 2;
+
+			while self.readers[parity].load(Ordering::SeqCst) != 0 {
+				thread::yield_now();
+			}
+		}
+
+		// SAFETY: The old snapshot was made by `Arc::into_raw`, and no reader
+		// can still be about to take a strong reference to it.
+		drop(unsafe { Arc::from_raw(old) });
+
+		return previous;
+	}
+
+	/// Consumes the `ArcCurrentPrevious`, returning the held values.
+	pub fn into_inner(mut self) -> CurrentPrevious<Arc<T>> {
+		let snapshot = std::mem::replace(self.snapshot.get_mut(), ptr::null_mut());
+
+		// SAFETY: The snapshot was made by `Arc::into_raw`, and is no longer
+		// held by the tracker, so it is not freed again on drop.
+		let snapshot = unsafe { Arc::from_raw(snapshot) };
+
+		return Arc::try_unwrap(snapshot).unwrap_or_else(|snapshot| CurrentPrevious::clone(&snapshot));
+	}
+}
+
+impl <T> Drop for ArcCurrentPrevious<T> {
+	fn drop(&mut self) {
+		let snapshot = *self.snapshot.get_mut();
+
+		if !snapshot.is_null() {
+			// SAFETY: The snapshot was made by `Arc::into_raw`, and no reader
+			// can hold a reference to the tracker while it is dropped.
+			drop(unsafe { Arc::from_raw(snapshot) });
+		}
+	}
+}
+
+impl <T> From<Arc<T>> for ArcCurrentPrevious<T> {
+	fn from(initial: Arc<T>) -> Self {
+		return Self {
+			snapshot: AtomicPtr::new(Arc::into_raw(Arc::new(CurrentPrevious::new(initial))).cast_mut()),
+			generation: AtomicUsize::new(0),
+			readers: [AtomicUsize::new(0), AtomicUsize::new(0)],
+			writer: Mutex::new(()),
+			_values: PhantomData
+		};
+	}
+}
+
+impl <T: fmt::Debug> fmt::Debug for ArcCurrentPrevious<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (current, previous) = self.pair();
+
+		return f.debug_struct("ArcCurrentPrevious")
+			.field("current", &current)
+			.field("previous", &previous)
+			.finish();
+	}
+}
+
+impl <T: Default> Default for ArcCurrentPrevious<T> {
+	fn default() -> Self {
+		return Self::new(T::default());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use std::sync::atomic::AtomicBool;
+	use std::thread;
+
+	#[test]
+	fn readers_keep_their_values() {
+		let tracker = ArcCurrentPrevious::new(String::from("a"));
+
+		let held = tracker.current();
+
+		thread::scope(|scope| {
+			scope.spawn(|| {
+				tracker.update(String::from("b"));
+				tracker.update_arc(Arc::new(String::from("c")));
+			});
+		});
+
+		assert_eq!(held.as_str(), "a");
+		assert_eq!(tracker.previous().as_deref().map(String::as_str), Some("b"));
+		assert_eq!(tracker.load().current().as_str(), "c");
+		assert_eq!(tracker.update(String::from("d")).as_deref().map(String::as_str), Some("b"));
+		assert_eq!(tracker.into_inner().current().as_str(), "d");
+	}
+
+	#[test]
+	fn consistent_pairs() {
+		let tracker = ArcCurrentPrevious::new(0_u32);
+		let first = tracker.current();
+
+		thread::scope(|scope| {
+			for _ in 0..2 {
+				scope.spawn(|| {
+					while *tracker.current() < 10_000 {
+						if let (current, Some(previous)) = tracker.pair() {
+							assert_eq!(*current, *previous + 1);
+						}
+					}
+				});
+			}
+
+			scope.spawn(|| {
+				for value in 1..=10_000 {
+					tracker.update(value);
+				}
+			});
+		});
+
+		let values = tracker.into_inner();
+
+		assert_eq!(values.pair(), (&Arc::new(10_000), Some(&Arc::new(9_999))));
+		assert_eq!(Arc::strong_count(&first), 1);
+		assert_eq!(Arc::strong_count(values.current()), 1);
+	}
+
+	#[test]
+	fn updates_progress_under_constant_reads() {
+		let tracker = ArcCurrentPrevious::new(0_u32);
+		let done = AtomicBool::new(false);
+
+		thread::scope(|scope| {
+			for _ in 0..4 {
+				scope.spawn(|| {
+					while !done.load(Ordering::Relaxed) {
+						tracker.pair();
+					}
+				});
+			}
+
+			for value in 1..=1_000 {
+				tracker.update(value);
+			}
+
+			done.store(true, Ordering::Relaxed);
+		});
+
+		assert_eq!(*tracker.current(), 1_000);
+	}
+}
@@ -0,0 +1,19 @@
+//! Trackers that can be shared between threads.
+
+use std::sync::{Mutex, MutexGuard};
+
+mod arc;
+mod rwlock;
+mod shared;
+mod watch;
+
+pub use arc::ArcCurrentPrevious;
+pub use rwlock::RwLockCurrentPrevious;
+pub use shared::{ChangeEvent, SharedCurrentPrevious};
+pub use watch::{Changed, Subscription, Transition, Transitions, WatchedCurrentPrevious};
+
+/// Locks a mutex, recovering the values from a thread that panicked while
+/// holding it, as a `CurrentPrevious` is never left half-updated.
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+	return mutex.lock().unwrap_or_else(|error| error.into_inner());
+}
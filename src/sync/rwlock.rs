@@ -0,0 +1,114 @@
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::CurrentPrevious;
+
+/// A handle to a `CurrentPrevious` shared between threads behind a
+/// read-write lock, so that any number of threads can read it at once.
+///
+/// Clones of a `RwLockCurrentPrevious` are handles to the same values.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::sync::RwLockCurrentPrevious;
+/// let shared = RwLockCurrentPrevious::new(0);
+///
+/// shared.update(1);
+///
+/// let first = shared.read();
+/// let second = shared.read();
+///
+/// assert_eq!(first.current(), &1);
+/// assert_eq!(second.previous(), Some(&0));
+/// ```
+#[derive(Debug, Default)]
+pub struct RwLockCurrentPrevious<T> {
+	inner: Arc<RwLock<CurrentPrevious<T>>>
+}
+
+impl <T> RwLockCurrentPrevious<T> {
+	/// Creates a new `RwLockCurrentPrevious` holding the `initial` value as
+	/// its `current` value, with no `previous` value.
+	pub fn new(initial: T) -> Self {
+		return Self::from(CurrentPrevious::new(initial));
+	}
+
+	/// Locks the values for reading, blocking while they are locked for
+	/// writing. The returned guard derefs to the `CurrentPrevious`.
+	pub fn read(&self) -> RwLockReadGuard<'_, CurrentPrevious<T>> {
+		return self.inner.read().unwrap_or_else(|error| error.into_inner());
+	}
+
+	/// Locks the values for writing, blocking while they are locked at all.
+	/// The returned guard derefs to the `CurrentPrevious`.
+	pub fn write(&self) -> RwLockWriteGuard<'_, CurrentPrevious<T>> {
+		return self.inner.write().unwrap_or_else(|error| error.into_inner());
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value. Returns the old `previous` value, if there was one.
+	pub fn update(&self, new: T) -> Option<T> {
+		return self.write().update(new);
+	}
+
+	/// Gets a clone of the `current` value.
+	pub fn current_cloned(&self) -> T where T: Clone {
+		return self.read().current().clone();
+	}
+
+	/// Gets a clone of the `previous` value, if there is one.
+	pub fn previous_cloned(&self) -> Option<T> where T: Clone {
+		return self.read().previous().cloned();
+	}
+}
+
+impl <T> Clone for RwLockCurrentPrevious<T> {
+	fn clone(&self) -> Self {
+		return Self {
+			inner: Arc::clone(&self.inner)
+		};
+	}
+}
+
+impl <T> From<CurrentPrevious<T>> for RwLockCurrentPrevious<T> {
+	fn from(current_previous: CurrentPrevious<T>) -> Self {
+		return Self {
+			inner: Arc::new(RwLock::new(current_previous))
+		};
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use std::sync::Barrier;
+	use std::thread;
+
+	#[test]
+	fn concurrent_readers() {
+		let shared = RwLockCurrentPrevious::new(String::from("a"));
+
+		shared.update(String::from("b"));
+
+		let barrier = Barrier::new(3);
+
+		thread::scope(|scope| {
+			for _ in 0..3 {
+				scope.spawn(|| {
+					let guard = shared.read();
+
+					// Every reader holds its guard until all of them have one.
+					barrier.wait();
+
+					assert_eq!(guard.current(), "b");
+				});
+			}
+		});
+
+		shared.write().clear_previous();
+
+		assert_eq!(shared.previous_cloned(), None);
+		assert_eq!(shared.clone().current_cloned(), "b");
+	}
+}
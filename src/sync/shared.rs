@@ -0,0 +1,307 @@
+use std::fmt;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+
+use crate::CurrentPrevious;
+
+use super::lock;
+
+/// A handle to a `CurrentPrevious` shared between threads behind a mutex.
+///
+/// Clones of a `SharedCurrentPrevious` are handles to the same values.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::sync::SharedCurrentPrevious;
+/// # use std::thread;
+/// let shared = SharedCurrentPrevious::new(0);
+///
+/// let handle = shared.clone();
+///
+/// thread::spawn(move || {
+///     handle.update(1);
+/// }).join().unwrap();
+///
+/// assert_eq!(shared.current_cloned(), 1);
+/// assert_eq!(shared.previous_cloned(), Some(0));
+/// ```
+#[derive(Debug, Default)]
+pub struct SharedCurrentPrevious<T> {
+	inner: Arc<Mutex<Inner<T>>>
+}
+
+/// An update of a [`SharedCurrentPrevious`], sent to the channels attached
+/// with [`SharedCurrentPrevious::attach`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChangeEvent<T> {
+	/// The value held before the update.
+	pub from: T,
+	/// The value held after the update.
+	pub to: T
+}
+
+/// Called with the `previous` and `current` values after each update,
+/// returning whether it should be kept for later updates.
+type Listener<T> = Box<dyn FnMut(&T, &T) -> bool + Send>;
+
+#[derive(Default)]
+struct Inner<T> {
+	values: CurrentPrevious<T>,
+	listeners: Vec<Listener<T>>
+}
+
+impl <T> Inner<T> {
+	fn notify(&mut self) {
+		let (current, previous) = self.values.pair();
+
+		let Some(previous) = previous else {
+			return;
+		};
+
+		self.listeners.retain_mut(|listener| listener(previous, current));
+	}
+}
+
+impl <T: fmt::Debug> fmt::Debug for Inner<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		return f.debug_struct("Inner")
+			.field("values", &self.values)
+			.field("listeners", &self.listeners.len())
+			.finish();
+	}
+}
+
+impl <T> SharedCurrentPrevious<T> {
+	/// Creates a new `SharedCurrentPrevious` holding the `initial` value as
+	/// its `current` value, with no `previous` value.
+	pub fn new(initial: T) -> Self {
+		return Self::from(CurrentPrevious::new(initial));
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value. Returns the old `previous` value, if there was one.
+	pub fn update(&self, new: T) -> Option<T> {
+		let mut inner = lock(&self.inner);
+
+		let evicted = inner.values.update(new);
+
+		inner.notify();
+
+		return evicted;
+	}
+
+	/// Sets the `current` value to the result of calling `f` with the old
+	/// `current` value, as though it were passed to [`update`](Self::update),
+	/// holding the lock so that no other update is made in between.
+	pub fn update_with(&self, f: impl FnOnce(&T) -> T) -> Option<T> {
+		let mut inner = lock(&self.inner);
+
+		let evicted = inner.values.update_with(f);
+
+		inner.notify();
+
+		return evicted;
+	}
+
+	/// Gets a clone of the `current` value.
+	pub fn current_cloned(&self) -> T where T: Clone {
+		return lock(&self.inner).values.current().clone();
+	}
+
+	/// Gets a clone of the `previous` value, if there is one.
+	pub fn previous_cloned(&self) -> Option<T> where T: Clone {
+		return lock(&self.inner).values.previous().cloned();
+	}
+
+	/// Calls `f` with a reference to the values, holding the lock until it
+	/// returns.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::sync::SharedCurrentPrevious;
+	/// let shared = SharedCurrentPrevious::new(String::from("a"));
+	///
+	/// shared.update(String::from("b"));
+	///
+	/// assert!(shared.with(|current_previous| current_previous.previous().is_some_and(|previous| previous == "a")));
+	/// ```
+	pub fn with<R>(&self, f: impl FnOnce(&CurrentPrevious<T>) -> R) -> R {
+		return f(&lock(&self.inner).values);
+	}
+
+	/// Calls `f` with a mutable reference to the values, holding the lock
+	/// until it returns.
+	///
+	/// Changes made by `f` are not seen by the registered closures and
+	/// attached channels, which are only notified of updates made with
+	/// [`update`](Self::update) and [`update_with`](Self::update_with).
+	pub fn with_mut<R>(&self, f: impl FnOnce(&mut CurrentPrevious<T>) -> R) -> R {
+		return f(&mut lock(&self.inner).values);
+	}
+
+	/// Registers a closure that is called with the `previous` and `current`
+	/// values after every later [`update`](Self::update) and
+	/// [`update_with`](Self::update_with), on the thread that made the update.
+	///
+	/// The closure is called while the values are locked, so it must not use
+	/// this `SharedCurrentPrevious`, or any clone of it, as that would
+	/// deadlock.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::sync::SharedCurrentPrevious;
+	/// # use std::sync::{Arc, Mutex};
+	/// # use std::thread;
+	/// let shared = SharedCurrentPrevious::new(20);
+	/// let log = Arc::new(Mutex::new(Vec::new()));
+	///
+	/// let observer_log = Arc::clone(&log);
+	///
+	/// thread::spawn({
+	///     let shared = shared.clone();
+	///
+	///     move || shared.on_change(move |old, new| observer_log.lock().unwrap().push(format!("{old} -> {new}")))
+	/// }).join().unwrap();
+	///
+	/// shared.update(25);
+	///
+	/// assert_eq!(*log.lock().unwrap(), vec!["20 -> 25"]);
+	/// ```
+	pub fn on_change(&self, mut f: impl FnMut(&T, &T) + Send + 'static) {
+		lock(&self.inner).listeners.push(Box::new(move |old, new| {
+			f(old, new);
+
+			return true;
+		}));
+	}
+
+	/// Attaches a channel that is sent a [`ChangeEvent`] for every later
+	/// [`update`](Self::update) and [`update_with`](Self::update_with). The
+	/// channel is detached once its receiver is dropped.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::sync::{ChangeEvent, SharedCurrentPrevious};
+	/// # use std::sync::mpsc;
+	/// # use std::thread;
+	/// let shared = SharedCurrentPrevious::new(0);
+	///
+	/// let (sender, receiver) = mpsc::channel();
+	///
+	/// shared.attach(sender);
+	///
+	/// let producer = shared.clone();
+	///
+	/// thread::spawn(move || {
+	///     producer.update(1);
+	///     producer.update(2);
+	/// });
+	///
+	/// assert_eq!(receiver.recv(), Ok(ChangeEvent { from: 0, to: 1 }));
+	/// assert_eq!(receiver.recv(), Ok(ChangeEvent { from: 1, to: 2 }));
+	/// ```
+	pub fn attach(&self, sender: Sender<ChangeEvent<T>>) where T: Clone + Send + 'static {
+		lock(&self.inner).listeners.push(Box::new(move |from, to| {
+			return sender.send(ChangeEvent {
+				from: from.clone(),
+				to: to.clone()
+			}).is_ok();
+		}));
+	}
+}
+
+impl <T> Clone for SharedCurrentPrevious<T> {
+	fn clone(&self) -> Self {
+		return Self {
+			inner: Arc::clone(&self.inner)
+		};
+	}
+}
+
+impl <T> From<CurrentPrevious<T>> for SharedCurrentPrevious<T> {
+	fn from(current_previous: CurrentPrevious<T>) -> Self {
+		return Self {
+			inner: Arc::new(Mutex::new(Inner {
+				values: current_previous,
+				listeners: Vec::new()
+			}))
+		};
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use std::sync::mpsc;
+	use std::thread;
+
+	#[test]
+	fn shared_between_threads() {
+		let shared = SharedCurrentPrevious::new(0);
+
+		thread::scope(|scope| {
+			for _ in 0..4 {
+				let shared = shared.clone();
+
+				scope.spawn(move || {
+					for _ in 0..100 {
+						shared.update_with(|current| current + 1);
+					}
+				});
+			}
+		});
+
+		assert_eq!(shared.current_cloned(), 400);
+		assert_eq!(shared.previous_cloned(), Some(399));
+	}
+
+	#[test]
+	fn attached_channels() {
+		let shared = SharedCurrentPrevious::new("a");
+
+		let (first, first_events) = mpsc::channel();
+		let (second, second_events) = mpsc::channel();
+
+		shared.attach(first);
+		shared.attach(second);
+
+		shared.update("b");
+		shared.with_mut(|current_previous| current_previous.extend(["x", "y"]));
+		shared.with_mut(|current_previous| current_previous.reset("b"));
+
+		drop(second_events);
+
+		shared.update_with(|_| "c");
+
+		assert_eq!(first_events.try_iter().collect::<Vec<_>>(), vec![ChangeEvent { from: "a", to: "b" }, ChangeEvent { from: "b", to: "c" }]);
+		assert_eq!(lock(&shared.inner).listeners.len(), 1);
+	}
+
+	#[test]
+	fn on_change() {
+		let shared = SharedCurrentPrevious::new(0);
+		let total = Arc::new(Mutex::new(0));
+
+		let observed = Arc::clone(&total);
+
+		shared.on_change(move |old, new| *lock(&observed) += new - old);
+
+		thread::scope(|scope| {
+			for _ in 0..4 {
+				scope.spawn(|| {
+					for _ in 0..25 {
+						shared.update_with(|current| current + 2);
+					}
+				});
+			}
+		});
+
+		assert_eq!(*lock(&total), 200);
+		assert_eq!(shared.current_cloned(), 200);
+	}
+}
@@ -0,0 +1,470 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+use crate::tracked::Tracked;
+
+use super::lock;
+
+/// Tracks a `current` value along with the `previous` value that it replaced,
+/// publishing each update to any number of [`Subscription`]s, which can wait
+/// on other threads for the values to change.
+///
+/// Like a watch channel, a subscription only sees the latest update: one
+/// that falls behind skips straight to the latest `(previous, current)` pair.
+/// Once the `WatchedCurrentPrevious` is dropped, its subscriptions stop
+/// waiting. Async tasks can instead await the next update with
+/// [`changed`](Self::changed), or consume every update they see as
+/// [`transitions`](Self::transitions).
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::sync::WatchedCurrentPrevious;
+/// # use std::thread;
+/// let watched = WatchedCurrentPrevious::new("idle");
+///
+/// let mut subscription = watched.subscribe();
+///
+/// let watcher = thread::spawn(move || subscription.changed());
+///
+/// watched.update("running");
+///
+/// assert_eq!(watcher.join().unwrap(), Some(("idle", "running")));
+/// ```
+#[derive(Debug)]
+pub struct WatchedCurrentPrevious<T> {
+	shared: Arc<Shared<T>>
+}
+
+/// A subscription to the updates of a [`WatchedCurrentPrevious`].
+///
+/// This `struct` is created by [`WatchedCurrentPrevious::subscribe`].
+#[derive(Debug)]
+pub struct Subscription<T> {
+	shared: Arc<Shared<T>>,
+	seen: u64
+}
+
+/// A change from the `previous` value to the `current` value, as seen by
+/// [`Transitions`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Transition<T> {
+	/// The value held before the update.
+	pub previous: T,
+	/// The value held after the update.
+	pub current: T
+}
+
+/// The sequence of updates of a [`WatchedCurrentPrevious`], as
+/// [`Transition`]s.
+///
+/// Like a [`Subscription`], a `Transitions` only sees the latest update, and
+/// ends once the [`WatchedCurrentPrevious`] is dropped. It can be iterated on
+/// a thread, which blocks while waiting for an update, or polled from an
+/// async task with [`poll_next`](Self::poll_next), which has the signature of
+/// `Stream::poll_next`, so it can be wrapped as a stream.
+///
+/// This `struct` is created by [`WatchedCurrentPrevious::transitions`].
+#[derive(Debug)]
+pub struct Transitions<T> {
+	subscription: Subscription<T>
+}
+
+/// A future that resolves once a [`WatchedCurrentPrevious`] is next updated.
+///
+/// This `struct` is created by [`WatchedCurrentPrevious::changed`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless awaited"]
+pub struct Changed<'a, T> {
+	shared: &'a Shared<T>,
+	seen: u64
+}
+
+#[derive(Debug)]
+struct Shared<T> {
+	state: Mutex<State<T>>,
+	changed: Condvar
+}
+
+#[derive(Debug)]
+struct State<T> {
+	values: Tracked<T>,
+	wakers: Vec<Waker>,
+	closed: bool
+}
+
+impl <T> WatchedCurrentPrevious<T> {
+	/// Creates a new `WatchedCurrentPrevious` holding the `initial` value as
+	/// its `current` value, with no `previous` value.
+	pub fn new(initial: T) -> Self {
+		return Self {
+			shared: Arc::new(Shared {
+				state: Mutex::new(State {
+					values: Tracked::new(initial),
+					wakers: Vec::new(),
+					closed: false
+				}),
+				changed: Condvar::new()
+			})
+		};
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value, and wakes every subscription waiting for a change.
+	/// Returns the old `previous` value, if there was one.
+	pub fn update(&self, new: T) -> Option<T> {
+		let (evicted, wakers) = {
+			let mut state = lock(&self.shared.state);
+
+			(state.values.update(new), std::mem::take(&mut state.wakers))
+		};
+
+		self.shared.changed.notify_all();
+
+		for waker in wakers {
+			waker.wake();
+		}
+
+		return evicted;
+	}
+
+	/// Gets a clone of the `current` value.
+	pub fn current_cloned(&self) -> T where T: Clone {
+		return lock(&self.shared.state).values.current().clone();
+	}
+
+	/// Gets a clone of the `previous` value, if there is one.
+	pub fn previous_cloned(&self) -> Option<T> where T: Clone {
+		return lock(&self.shared.state).values.previous().cloned();
+	}
+
+	/// Returns a future that resolves the next time the
+	/// `WatchedCurrentPrevious` is updated after this is called.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::sync::WatchedCurrentPrevious;
+	/// # use std::future::Future;
+	/// # use std::pin::pin;
+	/// # use std::sync::Arc;
+	/// # use std::task::{Context, Poll, Wake, Waker};
+	/// # use std::thread::{self, Thread};
+	/// # struct Unpark(Thread);
+	/// # impl Wake for Unpark {
+	/// #     fn wake(self: Arc<Self>) {
+	/// #         self.0.unpark();
+	/// #     }
+	/// # }
+	/// # fn block_on<F: Future>(future: F) -> F::Output {
+	/// #     let waker = Waker::from(Arc::new(Unpark(thread::current())));
+	/// #     let mut future = pin!(future);
+	/// #     loop {
+	/// #         if let Poll::Ready(output) = future.as_mut().poll(&mut Context::from_waker(&waker)) {
+	/// #             return output;
+	/// #         }
+	/// #         thread::park();
+	/// #     }
+	/// # }
+	/// let watched = WatchedCurrentPrevious::new(0);
+	///
+	/// thread::scope(|scope| {
+	///     let changed = watched.changed();
+	///
+	///     scope.spawn(|| watched.update(1));
+	///
+	///     block_on(changed);
+	/// });
+	///
+	/// assert_eq!(watched.current_cloned(), 1);
+	/// ```
+	pub fn changed(&self) -> Changed<'_, T> {
+		return Changed {
+			seen: lock(&self.shared.state).values.update_count(),
+			shared: &self.shared
+		};
+	}
+
+	/// Returns the sequence of updates made after this is called, as
+	/// [`Transition`]s.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::sync::{Transition, WatchedCurrentPrevious};
+	/// let watched = WatchedCurrentPrevious::new(0);
+	///
+	/// let transitions = watched.transitions();
+	///
+	/// watched.update(1);
+	/// drop(watched);
+	///
+	/// let increases: Vec<_> = transitions.filter(|transition| transition.current > transition.previous).collect();
+	///
+	/// assert_eq!(increases, vec![Transition { previous: 0, current: 1 }]);
+	/// ```
+	pub fn transitions(&self) -> Transitions<T> {
+		return Transitions {
+			subscription: self.subscribe()
+		};
+	}
+
+	/// Creates a new [`Subscription`] that sees the updates made after it was
+	/// created.
+	pub fn subscribe(&self) -> Subscription<T> {
+		return Subscription {
+			seen: lock(&self.shared.state).values.update_count(),
+			shared: Arc::clone(&self.shared)
+		};
+	}
+}
+
+impl <T> Drop for WatchedCurrentPrevious<T> {
+	fn drop(&mut self) {
+		let wakers = {
+			let mut state = lock(&self.shared.state);
+
+			state.closed = true;
+
+			std::mem::take(&mut state.wakers)
+		};
+
+		self.shared.changed.notify_all();
+
+		for waker in wakers {
+			waker.wake();
+		}
+	}
+}
+
+impl <T: Clone> Subscription<T> {
+	/// Waits for an update that this subscription has not seen, returning the
+	/// latest `(previous, current)` pair. Returns `None` if the
+	/// [`WatchedCurrentPrevious`] was dropped without another update.
+	pub fn changed(&mut self) -> Option<(T, T)> {
+		let state = self.shared.changed.wait_while(lock(&self.shared.state), |state| !is_unseen(self.seen, state) && !state.closed).unwrap_or_else(|error| error.into_inner());
+
+		return see(&mut self.seen, state);
+	}
+
+	/// Waits up to `timeout` for an update that this subscription has not
+	/// seen, returning the latest `(previous, current)` pair. Returns `None`
+	/// if there was none in time, or if the [`WatchedCurrentPrevious`] was
+	/// dropped without another update.
+	pub fn changed_timeout(&mut self, timeout: Duration) -> Option<(T, T)> {
+		let deadline = Instant::now() + timeout;
+		let mut state = lock(&self.shared.state);
+
+		while !is_unseen(self.seen, &state) && !state.closed {
+			let remaining = deadline.saturating_duration_since(Instant::now());
+
+			if remaining.is_zero() {
+				return None;
+			}
+
+			state = self.shared.changed.wait_timeout(state, remaining).unwrap_or_else(|error| error.into_inner()).0;
+		}
+
+		return see(&mut self.seen, state);
+	}
+
+	/// Returns the latest `(previous, current)` pair if there is an update
+	/// that this subscription has not seen, without waiting.
+	pub fn try_changed(&mut self) -> Option<(T, T)> {
+		let state = lock(&self.shared.state);
+
+		return see(&mut self.seen, state);
+	}
+}
+
+fn is_unseen<T>(seen: u64, state: &State<T>) -> bool {
+	return state.values.update_count() > seen;
+}
+
+/// Marks the latest update as seen, returning its `(previous, current)` pair,
+/// if it has not been seen already.
+fn see<T: Clone>(seen: &mut u64, state: MutexGuard<'_, State<T>>) -> Option<(T, T)> {
+	if !is_unseen(*seen, &state) {
+		return None;
+	}
+
+	*seen = state.values.update_count();
+
+	let (current, previous) = state.values.values().pair();
+
+	return Some((previous.expect("an updated value has a previous value").clone(), current.clone()));
+}
+
+impl <T: Clone> Transitions<T> {
+	/// Attempts to get the next [`Transition`], registering the current task
+	/// to be woken by the next update if there is none yet. Returns
+	/// `Poll::Ready(None)` once the [`WatchedCurrentPrevious`] has been
+	/// dropped without another update.
+	pub fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Transition<T>>> {
+		let subscription = &mut self.subscription;
+		let mut state = lock(&subscription.shared.state);
+
+		if !is_unseen(subscription.seen, &state) && !state.closed {
+			if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+				state.wakers.push(cx.waker().clone());
+			}
+
+			return Poll::Pending;
+		}
+
+		return Poll::Ready(see(&mut subscription.seen, state).map(Transition::from));
+	}
+}
+
+impl <T: Clone> Iterator for Transitions<T> {
+	type Item = Transition<T>;
+
+	fn next(&mut self) -> Option<Transition<T>> {
+		return self.subscription.changed().map(Transition::from);
+	}
+}
+
+impl <T> From<(T, T)> for Transition<T> {
+	fn from((previous, current): (T, T)) -> Self {
+		return Self {
+			previous,
+			current
+		};
+	}
+}
+
+impl <T> Future for Changed<'_, T> {
+	type Output = ();
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+		let mut state = lock(&self.shared.state);
+
+		if is_unseen(self.seen, &state) {
+			return Poll::Ready(());
+		}
+
+		if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+			state.wakers.push(cx.waker().clone());
+		}
+
+		return Poll::Pending;
+	}
+}
+
+impl <T> Clone for Subscription<T> {
+	fn clone(&self) -> Self {
+		return Self {
+			shared: Arc::clone(&self.shared),
+			seen: self.seen
+		};
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use std::thread;
+
+	#[test]
+	fn latest_update() {
+		let watched = WatchedCurrentPrevious::new(0);
+
+		let mut subscription = watched.subscribe();
+
+		assert_eq!(subscription.try_changed(), None);
+		assert_eq!(subscription.changed_timeout(Duration::from_millis(10)), None);
+
+		watched.update(1);
+		watched.update(2);
+
+		let mut late = watched.subscribe();
+
+		assert_eq!(subscription.clone().try_changed(), Some((1, 2)));
+		assert_eq!(subscription.changed(), Some((1, 2)));
+		assert_eq!(subscription.try_changed(), None);
+		assert_eq!(late.try_changed(), None);
+		assert_eq!(watched.previous_cloned(), Some(1));
+
+		let waiter = thread::spawn(move || (subscription.changed(), subscription.changed()));
+
+		watched.update(3);
+
+		drop(watched);
+
+		let (first, second) = waiter.join().unwrap();
+
+		assert_eq!(first, Some((2, 3)));
+		assert_eq!(second, None);
+		assert_eq!(late.changed(), Some((2, 3)));
+		assert_eq!(late.changed(), None);
+	}
+
+	#[test]
+	fn changed_future() {
+		use std::task::Wake;
+
+		struct Count(Mutex<u32>);
+
+		impl Wake for Count {
+			fn wake(self: Arc<Self>) {
+				*lock(&self.0) += 1;
+			}
+		}
+
+		let count = Arc::new(Count(Mutex::new(0)));
+		let waker = Waker::from(Arc::clone(&count));
+		let mut cx = Context::from_waker(&waker);
+
+		let watched = WatchedCurrentPrevious::new("a");
+
+		let mut changed = watched.changed();
+
+		assert_eq!(Pin::new(&mut changed).poll(&mut cx), Poll::Pending);
+		assert_eq!(Pin::new(&mut changed).poll(&mut cx), Poll::Pending);
+
+		watched.update("b");
+
+		assert_eq!(*lock(&count.0), 1);
+		assert_eq!(Pin::new(&mut changed).poll(&mut cx), Poll::Ready(()));
+		assert_eq!(Pin::new(&mut watched.changed()).poll(&mut cx), Poll::Pending);
+	}
+
+	#[test]
+	fn transitions() {
+		use std::task::Wake;
+
+		struct Ignore;
+
+		impl Wake for Ignore {
+			fn wake(self: Arc<Self>) {}
+		}
+
+		let waker = Waker::from(Arc::new(Ignore));
+		let mut cx = Context::from_waker(&waker);
+
+		let watched = WatchedCurrentPrevious::new(String::from("a"));
+
+		let mut transitions = watched.transitions();
+
+		assert_eq!(Pin::new(&mut transitions).poll_next(&mut cx), Poll::Pending);
+
+		watched.update(String::from("b"));
+
+		assert_eq!(Pin::new(&mut transitions).poll_next(&mut cx), Poll::Ready(Some(Transition {
+			previous: String::from("a"),
+			current: String::from("b")
+		})));
+		assert_eq!(Pin::new(&mut transitions).poll_next(&mut cx), Poll::Pending);
+
+		watched.update(String::from("c"));
+		drop(watched);
+
+		assert_eq!(transitions.next().map(|transition| transition.current), Some(String::from("c")));
+		assert_eq!(Pin::new(&mut transitions).poll_next(&mut cx), Poll::Ready(None));
+		assert_eq!(transitions.next(), None);
+	}
+}
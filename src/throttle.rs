@@ -0,0 +1,123 @@
+//! Limiting of how often updates are committed.
+
+use std::time::Duration;
+
+use crate::CurrentPrevious;
+use crate::clock::{Clock, MonotonicClock, Timestamp};
+
+/// Tracks a `current` value along with the `previous` value that it replaced,
+/// committing at most one update per interval and dropping the rest.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::clock::MockClock;
+/// # use current_previous::throttle::Throttled;
+/// # use std::time::Duration;
+/// let clock = MockClock::new();
+///
+/// let mut throttled = Throttled::with_clock(0, Duration::from_secs(1), clock.clone());
+///
+/// assert!(throttled.update(1));
+/// assert!(!throttled.update(2));
+///
+/// clock.advance(Duration::from_secs(1));
+///
+/// assert!(throttled.update(3));
+/// assert_eq!(throttled.current(), &3);
+/// assert_eq!(throttled.previous(), Some(&1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Throttled<T, C: Clock = MonotonicClock> {
+	values: CurrentPrevious<T>,
+	last_committed: Option<C::Timestamp>,
+	interval: Duration,
+	clock: C
+}
+
+impl <T> Throttled<T> {
+	/// Creates a new `Throttled` holding the `initial` value as its `current`
+	/// value, which commits at most one update per `interval`.
+	pub fn new(initial: T, interval: Duration) -> Self {
+		return Self::with_clock(initial, interval, MonotonicClock);
+	}
+}
+
+impl <T, C: Clock> Throttled<T, C> {
+	/// Creates a new `Throttled` holding the `initial` value as its `current`
+	/// value, which commits at most one update per `interval`, as told by the
+	/// given clock.
+	pub fn with_clock(initial: T, interval: Duration, clock: C) -> Self {
+		return Self {
+			values: CurrentPrevious::new(initial),
+			last_committed: None,
+			interval,
+			clock
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return self.values.current();
+	}
+
+	/// Gets an optional reference to the `previous` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.values.previous();
+	}
+
+	/// Gets a reference to the held values.
+	pub fn values(&self) -> &CurrentPrevious<T> {
+		return &self.values;
+	}
+
+	/// Gets the minimum time between committed updates.
+	pub fn interval(&self) -> Duration {
+		return self.interval;
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value, unless an update was committed less than the
+	/// interval ago, in which case the new value is dropped. Returns whether
+	/// the update was committed.
+	pub fn update(&mut self, new: T) -> bool {
+		let now = self.clock.now();
+
+		if self.last_committed.is_some_and(|at| now.elapsed_since(&at) < self.interval) {
+			return false;
+		}
+
+		self.values.update(new);
+		self.last_committed = Some(now);
+
+		return true;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::clock::MockClock;
+
+	#[test]
+	fn drop_within_interval() {
+		let clock = MockClock::new();
+
+		let mut throttled = Throttled::with_clock("a", Duration::from_millis(100), clock.clone());
+
+		assert!(throttled.update("b"));
+
+		clock.advance(Duration::from_millis(99));
+
+		assert!(!throttled.update("c"));
+		assert_eq!(throttled.current(), &"b");
+
+		clock.advance(Duration::from_millis(1));
+
+		assert!(throttled.update("d"));
+		assert!(!throttled.update("e"));
+		assert_eq!(throttled.previous(), Some(&"b"));
+		assert_eq!(throttled.interval(), Duration::from_millis(100));
+	}
+}
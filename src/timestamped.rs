@@ -0,0 +1,416 @@
+//! Tracking of when the `current` and `previous` values were recorded.
+
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+use crate::CurrentPrevious;
+use crate::clock::{Clock, MonotonicClock};
+use crate::num::ToF64;
+
+pub use crate::clock::Timestamp;
+
+/// Tracks a `current` value along with the `previous` value that it replaced,
+/// and the time at which each was recorded.
+///
+/// Times are told by a [`Clock`], which is the monotonic [`MonotonicClock`]
+/// by default. A [`SystemClock`](crate::clock::SystemClock) records
+/// wall-clock times instead, and a [`MockClock`](crate::clock::MockClock)
+/// allows time-dependent behavior to be tested deterministically. The clock and
+/// the `previous` value's TTL are not taken into account when comparing or
+/// hashing.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::timestamped::TimestampedCurrentPrevious;
+/// # use std::time::{Duration, Instant};
+/// let start = Instant::now();
+///
+/// let mut current_previous = TimestampedCurrentPrevious::new_at(0, start);
+///
+/// current_previous.update_at(1, start + Duration::from_secs(5));
+///
+/// assert_eq!(current_previous.current(), &1);
+/// assert_eq!(current_previous.current_at(), start + Duration::from_secs(5));
+/// assert_eq!(current_previous.previous(), Some(&0));
+/// assert_eq!(current_previous.previous_at(), Some(start));
+/// ```
+///
+/// Recording wall-clock times:
+///
+/// ```
+/// # use current_previous::clock::SystemClock;
+/// # use current_previous::timestamped::TimestampedCurrentPrevious;
+/// # use std::time::SystemTime;
+/// let current_previous = TimestampedCurrentPrevious::with_clock(0, SystemClock);
+///
+/// assert!(current_previous.current_at() <= SystemTime::now());
+/// ```
+#[derive(Clone, Debug)]
+pub struct TimestampedCurrentPrevious<T, C: Clock = MonotonicClock> {
+	values: CurrentPrevious<T>,
+	times: CurrentPrevious<C::Timestamp>,
+	previous_ttl: Option<Duration>,
+	clock: C
+}
+
+impl <T> TimestampedCurrentPrevious<T> {
+	/// Creates a new `TimestampedCurrentPrevious` holding the `initial` value
+	/// as its `current` value, recorded now.
+	pub fn new(initial: T) -> Self {
+		return Self::with_clock(initial, MonotonicClock);
+	}
+
+	/// Creates a new `TimestampedCurrentPrevious` holding the `initial` value
+	/// as its `current` value, recorded at the given time.
+	pub fn new_at(initial: T, at: Instant) -> Self {
+		return Self::with_clock_at(initial, MonotonicClock, at);
+	}
+}
+
+impl <T, C: Clock> TimestampedCurrentPrevious<T, C> {
+	/// Creates a new `TimestampedCurrentPrevious` holding the `initial` value
+	/// as its `current` value, which tells the time with the given clock,
+	/// recorded now.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::clock::MockClock;
+	/// # use current_previous::timestamped::TimestampedCurrentPrevious;
+	/// # use std::time::Duration;
+	/// let clock = MockClock::new();
+	///
+	/// let current_previous = TimestampedCurrentPrevious::with_clock(0, clock.clone());
+	///
+	/// clock.advance(Duration::from_secs(90));
+	///
+	/// assert_eq!(current_previous.age(), Duration::from_secs(90));
+	/// ```
+	pub fn with_clock(initial: T, clock: C) -> Self {
+		let at = clock.now();
+
+		return Self::with_clock_at(initial, clock, at);
+	}
+
+	/// Creates a new `TimestampedCurrentPrevious` holding the `initial` value
+	/// as its `current` value, which tells the time with the given clock,
+	/// recorded at the given time.
+	pub fn with_clock_at(initial: T, clock: C, at: C::Timestamp) -> Self {
+		return Self {
+			values: CurrentPrevious::new(initial),
+			times: CurrentPrevious::new(at),
+			previous_ttl: None,
+			clock
+		};
+	}
+
+	/// Sets a TTL for the `previous` value, after which it reads as `None`,
+	/// as it is too old to be meaningfully compared with the `current` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::clock::MockClock;
+	/// # use current_previous::timestamped::TimestampedCurrentPrevious;
+	/// # use std::time::Duration;
+	/// let clock = MockClock::new();
+	///
+	/// let mut current_previous = TimestampedCurrentPrevious::with_clock(0, clock.clone())
+	///     .with_previous_ttl(Duration::from_secs(60));
+	///
+	/// current_previous.update(1);
+	///
+	/// assert_eq!(current_previous.previous(), Some(&0));
+	///
+	/// clock.advance(Duration::from_secs(61));
+	///
+	/// assert_eq!(current_previous.previous(), None);
+	/// ```
+	pub fn with_previous_ttl(mut self, ttl: Duration) -> Self {
+		self.previous_ttl = Some(ttl);
+
+		return self;
+	}
+
+	/// Gets the TTL for the `previous` value, if one has been set.
+	pub fn previous_ttl(&self) -> Option<Duration> {
+		return self.previous_ttl;
+	}
+
+	/// Gets a reference to the clock that tells the time.
+	pub fn clock(&self) -> &C {
+		return &self.clock;
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return self.values.current();
+	}
+
+	/// Gets the time at which the `current` value was recorded.
+	pub fn current_at(&self) -> C::Timestamp {
+		return *self.times.current();
+	}
+
+	/// Gets an optional reference to the `previous` value, which is `None` if
+	/// it is older than the TTL.
+	pub fn previous(&self) -> Option<&T> {
+		self.previous_at()?;
+
+		return self.values.previous();
+	}
+
+	/// Gets the time at which the `previous` value was recorded, if there is
+	/// one that is no older than the TTL.
+	pub fn previous_at(&self) -> Option<C::Timestamp> {
+		let at = *self.times.previous()?;
+
+		if self.previous_ttl.is_some_and(|ttl| self.clock.now().elapsed_since(&at) > ttl) {
+			return None;
+		}
+
+		return Some(at);
+	}
+
+	/// Gets the time elapsed since the `current` value was recorded.
+	pub fn age(&self) -> Duration {
+		return self.clock.now().elapsed_since(&self.current_at());
+	}
+
+	/// Gets the time elapsed since the `previous` value was recorded, if there
+	/// is one.
+	pub fn previous_age(&self) -> Option<Duration> {
+		return self.previous_at().map(|at| self.clock.now().elapsed_since(&at));
+	}
+
+	/// Gets the time elapsed between the recording of the `previous` value and
+	/// the `current` value, if there is a `previous` value.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::timestamped::TimestampedCurrentPrevious;
+	/// # use std::time::{Duration, Instant};
+	/// let start = Instant::now();
+	///
+	/// let mut current_previous = TimestampedCurrentPrevious::new_at(0, start);
+	///
+	/// assert_eq!(current_previous.interval(), None);
+	///
+	/// current_previous.update_at(1, start + Duration::from_millis(250));
+	///
+	/// assert_eq!(current_previous.interval(), Some(Duration::from_millis(250)));
+	/// ```
+	pub fn interval(&self) -> Option<Duration> {
+		return Some(self.current_at().elapsed_since(&self.previous_at()?));
+	}
+
+	/// Returns whether the `current` value was recorded longer than `max_age`
+	/// ago.
+	pub fn is_stale(&self, max_age: Duration) -> bool {
+		return self.age() > max_age;
+	}
+
+	/// Computes the change from the `previous` value to the `current` value
+	/// per second elapsed between their recording. Returns `None` if there is
+	/// no `previous` value, or if both were recorded at the same time.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::timestamped::TimestampedCurrentPrevious;
+	/// # use std::time::{Duration, Instant};
+	/// let start = Instant::now();
+	///
+	/// let mut bytes_sent = TimestampedCurrentPrevious::new_at(1000_u64, start);
+	///
+	/// bytes_sent.update_at(3000, start + Duration::from_secs(4));
+	///
+	/// assert_eq!(bytes_sent.rate_of_change(), Some(500.0));
+	/// ```
+	pub fn rate_of_change(&self) -> Option<f64> where T: ToF64 {
+		let seconds = self.interval()?.as_secs_f64();
+
+		if seconds == 0.0 {
+			return None;
+		}
+
+		return Some((self.current().to_f64() - self.previous()?.to_f64()) / seconds);
+	}
+
+	/// Gets a reference to the held values, without their times, including a
+	/// `previous` value older than the TTL.
+	pub fn values(&self) -> &CurrentPrevious<T> {
+		return &self.values;
+	}
+
+	/// Sets a new `current` value, recorded now, making the old `current`
+	/// value the `previous` value. Returns the old `previous` value, if there
+	/// was one.
+	pub fn update(&mut self, new: T) -> Option<T> {
+		return self.update_at(new, self.clock.now());
+	}
+
+	/// Sets a new `current` value, recorded at the given time, making the old
+	/// `current` value the `previous` value. Returns the old `previous` value,
+	/// if there was one.
+	pub fn update_at(&mut self, new: T, at: C::Timestamp) -> Option<T> {
+		self.times.update(at);
+
+		return self.values.update(new);
+	}
+
+	/// Consumes the `TimestampedCurrentPrevious`, returning the held values
+	/// without their times.
+	pub fn into_values(self) -> CurrentPrevious<T> {
+		return self.values;
+	}
+}
+
+impl <T, C: Clock + Default> From<T> for TimestampedCurrentPrevious<T, C> {
+	fn from(initial: T) -> Self {
+		return Self::with_clock(initial, C::default());
+	}
+}
+
+impl <T: PartialEq, C: Clock> PartialEq for TimestampedCurrentPrevious<T, C> {
+	fn eq(&self, other: &Self) -> bool {
+		return self.values == other.values && self.times == other.times;
+	}
+}
+
+impl <T: Eq, C: Clock> Eq for TimestampedCurrentPrevious<T, C> {}
+
+impl <T: Hash, C: Clock> Hash for TimestampedCurrentPrevious<T, C> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.values.hash(state);
+		self.times.hash(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use std::time::SystemTime;
+
+	use crate::clock::{MockClock, SystemClock};
+
+	#[test]
+	fn update_records_times() {
+		let start = Instant::now();
+
+		let mut current_previous = TimestampedCurrentPrevious::new_at("a", start);
+
+		assert_eq!(current_previous.previous_at(), None);
+		assert_eq!(current_previous.update_at("b", start + Duration::from_secs(1)), None);
+		assert_eq!(current_previous.update_at("c", start + Duration::from_secs(2)), Some("a"));
+
+		assert_eq!(current_previous.previous(), Some(&"b"));
+		assert_eq!(current_previous.previous_at(), Some(start + Duration::from_secs(1)));
+
+		current_previous.update("d");
+
+		assert!(current_previous.current_at() >= start);
+		assert_eq!(current_previous.into_values().into_parts(), ("d", Some("c")));
+	}
+
+	#[test]
+	fn age() {
+		let clock = MockClock::new();
+
+		let mut current_previous = TimestampedCurrentPrevious::with_clock(0, clock.clone());
+
+		clock.advance(Duration::from_secs(60));
+
+		assert_eq!(current_previous.age(), Duration::from_secs(60));
+		assert_eq!(current_previous.previous_age(), None);
+		assert!(current_previous.is_stale(Duration::from_secs(30)));
+
+		current_previous.update(1);
+		clock.advance(Duration::from_secs(10));
+
+		assert_eq!(current_previous.age(), Duration::from_secs(10));
+		assert!(!current_previous.is_stale(Duration::from_secs(30)));
+		assert_eq!(current_previous.previous_age(), Some(Duration::from_secs(70)));
+	}
+
+	#[test]
+	fn system_time() {
+		let start = SystemTime::UNIX_EPOCH;
+
+		let mut current_previous = TimestampedCurrentPrevious::with_clock_at(0, SystemClock, start + Duration::from_secs(10));
+
+		current_previous.update_at(1, start);
+
+		assert_eq!(current_previous.previous_at(), Some(start + Duration::from_secs(10)));
+		assert_eq!(current_previous.current_at().elapsed_since(&current_previous.previous_at().unwrap()), Duration::ZERO);
+		assert!(current_previous.age() > Duration::from_secs(10));
+	}
+
+	#[test]
+	fn rate_of_change() {
+		let start = Instant::now();
+
+		let mut current_previous = TimestampedCurrentPrevious::new_at(10.0, start);
+
+		assert_eq!(current_previous.rate_of_change(), None);
+
+		current_previous.update_at(4.0, start + Duration::from_millis(500));
+
+		assert_eq!(current_previous.rate_of_change(), Some(-12.0));
+
+		current_previous.update_at(5.0, start + Duration::from_millis(500));
+
+		assert_eq!(current_previous.rate_of_change(), None);
+	}
+
+	#[test]
+	fn mock_clock() {
+		let clock = MockClock::new();
+
+		let mut current_previous = TimestampedCurrentPrevious::with_clock("a", clock.clone());
+
+		clock.advance(Duration::from_secs(2));
+		current_previous.update("b");
+		clock.advance(Duration::from_secs(3));
+
+		assert_eq!(current_previous.age(), Duration::from_secs(3));
+		assert_eq!(current_previous.previous_age(), Some(Duration::from_secs(5)));
+		assert!(current_previous.is_stale(Duration::from_secs(2)));
+		assert!(!current_previous.is_stale(Duration::from_secs(3)));
+
+		let mut other = TimestampedCurrentPrevious::with_clock_at("a", MockClock::new(), current_previous.previous_at().unwrap());
+
+		other.update_at("b", current_previous.current_at());
+
+		assert_eq!(current_previous, other);
+	}
+
+	#[test]
+	fn previous_ttl() {
+		let clock = MockClock::new();
+
+		let mut current_previous = TimestampedCurrentPrevious::with_clock(1.0, clock.clone()).with_previous_ttl(Duration::from_secs(10));
+
+		clock.advance(Duration::from_secs(5));
+		current_previous.update(2.0);
+
+		assert_eq!(current_previous.previous_ttl(), Some(Duration::from_secs(10)));
+		assert_eq!(current_previous.rate_of_change(), Some(0.2));
+
+		clock.advance(Duration::from_secs(6));
+
+		assert_eq!(current_previous.previous(), None);
+		assert_eq!(current_previous.previous_at(), None);
+		assert_eq!(current_previous.previous_age(), None);
+		assert_eq!(current_previous.interval(), None);
+		assert_eq!(current_previous.rate_of_change(), None);
+		assert_eq!(current_previous.values().previous(), Some(&1.0));
+
+		current_previous.update(3.0);
+
+		assert_eq!(current_previous.previous(), Some(&2.0));
+	}
+}
@@ -0,0 +1,245 @@
+//! Change tracking on top of a `CurrentPrevious`, for polling whether and how
+//! often it was updated.
+
+use crate::CurrentPrevious;
+
+/// Tracks a `current` value along with the `previous` value that it replaced,
+/// along with a dirty flag and a count of the updates made.
+///
+/// The dirty flag is set by every update and cleared by
+/// [`mark_clean`](Self::mark_clean), so that a frame-based system can cheaply
+/// poll whether the values changed since it last looked, independent of
+/// `PartialEq`. The update count also sees updates that set the value back
+/// to what it was.
+///
+/// # Examples
+///
+/// ```
+/// # use current_previous::tracked::Tracked;
+/// let mut tracked = Tracked::new(0);
+///
+/// assert!(!tracked.is_dirty());
+///
+/// tracked.update(1);
+/// tracked.update(0);
+///
+/// assert!(tracked.is_dirty());
+/// assert_eq!(tracked.update_count(), 2);
+///
+/// tracked.mark_clean();
+///
+/// assert!(!tracked.is_dirty());
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Tracked<T> {
+	values: CurrentPrevious<T>,
+	dirty: bool,
+	updates: u64
+}
+
+impl <T> Tracked<T> {
+	/// Creates a new `Tracked` holding the `initial` value as its `current`
+	/// value, with no `previous` value, which is clean and has no updates.
+	pub const fn new(initial: T) -> Self {
+		return Self::from_values(CurrentPrevious::new(initial));
+	}
+
+	const fn from_values(values: CurrentPrevious<T>) -> Self {
+		return Self {
+			values,
+			dirty: false,
+			updates: 0
+		};
+	}
+
+	/// Gets a reference to the `current` value.
+	pub fn current(&self) -> &T {
+		return self.values.current();
+	}
+
+	/// Gets an optional reference to the `previous` value.
+	pub fn previous(&self) -> Option<&T> {
+		return self.values.previous();
+	}
+
+	/// Gets a reference to the held values.
+	pub fn values(&self) -> &CurrentPrevious<T> {
+		return &self.values;
+	}
+
+	/// Consumes the `Tracked`, returning the held values.
+	pub fn into_values(self) -> CurrentPrevious<T> {
+		return self.values;
+	}
+
+	/// Sets a new `current` value, making the old `current` value the
+	/// `previous` value, and marks the `Tracked` as dirty. Returns the old
+	/// `previous` value, if there was one.
+	pub fn update(&mut self, new: T) -> Option<T> {
+		let previous = self.values.update(new);
+
+		self.record();
+
+		return previous;
+	}
+
+	/// Sets the `current` value to the result of calling `f` with the old
+	/// `current` value, as though it were passed to [`update`](Self::update).
+	pub fn update_with(&mut self, f: impl FnOnce(&T) -> T) -> Option<T> {
+		let previous = self.values.update_with(f);
+
+		self.record();
+
+		return previous;
+	}
+
+	/// Mutates the `current` value in place, keeping a clone of the old
+	/// `current` value as the `previous` value, as though the mutated value
+	/// were passed to [`update`](Self::update).
+	pub fn update_in_place(&mut self, f: impl FnOnce(&mut T)) -> Option<T> where T: Clone {
+		let previous = self.values.update_in_place(f);
+
+		self.record();
+
+		return previous;
+	}
+
+	/// Updates with the `new` value only if it differs from the `current`
+	/// value. Returns whether the update happened.
+	pub fn update_if_changed(&mut self, new: T) -> bool where T: PartialEq {
+		if !self.values.update_if_changed(new) {
+			return false;
+		}
+
+		self.record();
+
+		return true;
+	}
+
+	/// Replaces the held values with the `new` value and no `previous` value,
+	/// marking the `Tracked` as clean and setting its update count to zero.
+	pub fn reset(&mut self, new: T) {
+		*self = Self::new(new);
+	}
+
+	/// Returns whether the `Tracked` was updated since it was created, reset,
+	/// or marked as clean.
+	pub fn is_dirty(&self) -> bool {
+		return self.dirty;
+	}
+
+	/// Marks the `Tracked` as clean, so that [`is_dirty`](Self::is_dirty)
+	/// returns `false` until the next update.
+	pub fn mark_clean(&mut self) {
+		self.dirty = false;
+	}
+
+	/// Gets the number of updates made since the `Tracked` was created or
+	/// reset.
+	pub fn update_count(&self) -> u64 {
+		return self.updates;
+	}
+
+	/// Acknowledges the latest change by taking the `previous` value out and
+	/// marking the `Tracked` as clean.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # use current_previous::tracked::Tracked;
+	/// let mut tracked = Tracked::new(0);
+	///
+	/// tracked.update(1);
+	///
+	/// if tracked.is_dirty() {
+	///     assert_eq!(tracked.commit(), Some(0));
+	/// }
+	///
+	/// assert!(!tracked.is_dirty());
+	/// assert_eq!(tracked.previous(), None);
+	/// ```
+	pub fn commit(&mut self) -> Option<T> {
+		self.mark_clean();
+
+		return self.values.commit();
+	}
+
+	fn record(&mut self) {
+		self.dirty = true;
+		self.updates += 1;
+	}
+}
+
+impl <T> From<CurrentPrevious<T>> for Tracked<T> {
+	/// Starts tracking the given values, which are clean and have no updates.
+	fn from(values: CurrentPrevious<T>) -> Self {
+		return Self::from_values(values);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn dirty() {
+		let mut tracked = Tracked::new(0);
+
+		assert!(!tracked.is_dirty());
+
+		tracked.update_if_changed(0);
+
+		assert!(!tracked.is_dirty());
+
+		tracked.update_with(|current| current + 1);
+
+		assert!(tracked.is_dirty());
+
+		tracked.mark_clean();
+		tracked.update_in_place(|current| *current += 1);
+
+		assert!(tracked.is_dirty());
+		assert_eq!(tracked.values().pair(), (&2, Some(&1)));
+
+		tracked.reset(4);
+
+		assert!(!tracked.is_dirty());
+	}
+
+	#[test]
+	fn panicking_update() {
+		let mut tracked = Tracked::new(0);
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			tracked.update_with(|_| panic!("no new value"));
+		}));
+
+		assert!(result.is_err());
+		assert!(!tracked.is_dirty());
+		assert_eq!(tracked.update_count(), 0);
+	}
+
+	#[test]
+	fn update_count() {
+		let mut tracked = Tracked::from(CurrentPrevious::new_with_previous(true, Some(false)));
+
+		assert_eq!(tracked.update_count(), 0);
+
+		tracked.update(false);
+		tracked.update(true);
+		tracked.update_if_changed(true);
+
+		assert_eq!(tracked.update_count(), 2);
+
+		tracked.commit();
+		tracked.mark_clean();
+
+		assert_eq!(tracked.update_count(), 2);
+		assert_eq!(tracked.into_values().into_parts(), (true, None));
+	}
+
+	#[test]
+	fn plain_layout() {
+		assert_eq!(std::mem::size_of::<CurrentPrevious<u8>>(), std::mem::size_of::<(u8, Option<u8>)>());
+	}
+}